@@ -0,0 +1,41 @@
+use chrono::Local;
+use rusqlite::Connection;
+
+pub fn pick_active_sprint_id(conn: &Connection) -> Result<Option<String>, String> {
+    let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
+
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT id, start_date, end_date
+             FROM sprints
+             WHERE archived = 0
+             ORDER BY created_at DESC",
+        )
+        .map_err(|error| format!("failed to prepare active sprint query: {error}"))?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, Option<String>>(2)?,
+            ))
+        })
+        .map_err(|error| format!("failed to query active sprint: {error}"))?;
+
+    let newest_first = rows
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect sprints: {error}"))?;
+
+    let ongoing = newest_first.iter().find(|(_, start_date, end_date)| {
+        start_date.as_str() <= today.as_str()
+            && end_date
+                .as_deref()
+                .map(|end| end >= today.as_str())
+                .unwrap_or(true)
+    });
+
+    Ok(ongoing
+        .or_else(|| newest_first.first())
+        .map(|(id, _, _)| id.clone()))
+}
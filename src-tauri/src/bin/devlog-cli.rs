@@ -1,15 +1,31 @@
-use chrono::Utc;
-use rusqlite::{params, Connection};
-use std::collections::{BTreeMap, HashMap};
+#[path = "../active_sprint.rs"]
+mod active_sprint;
+#[path = "../defaults.rs"]
+mod defaults;
+#[path = "../markdown.rs"]
+mod markdown;
+#[path = "../title_length.rs"]
+mod title_length;
+
+use active_sprint::pick_active_sprint_id;
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use markdown::{escape_markdown, markdown_details_block};
+use rusqlite::{params, Connection, OptionalExtension};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::env;
 use std::fs;
 use std::io::{self, IsTerminal, Read, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
+use title_length::validate_title_length;
 
 const APP_IDENTIFIER: &str = "com.ahmadsaptan.devlogdesk";
 const DB_FILE_NAME: &str = "daily-updates.sqlite";
-const DEFAULT_TRUNCATE_LINES: usize = 30;
+const REPORT_DIRECTORY_SETTING: &str = "report_directory";
+const DEFAULT_TERMINAL_WIDTH: usize = 80;
+const DEFAULT_TERMINAL_HEIGHT: usize = 24;
+const TEXT_SCREEN_RESERVED_LINES: usize = 8;
+const ALL_DETAILS_RESERVED_LINES: usize = 10;
 
 #[derive(Debug, Clone)]
 struct Sprint {
@@ -18,6 +34,8 @@ struct Sprint {
     name: String,
     start_date: String,
     end_date: Option<String>,
+    description: Option<String>,
+    entry_count: i64,
 }
 
 #[derive(Debug, Clone)]
@@ -26,6 +44,7 @@ struct DailyEntry {
     category_id: String,
     title: String,
     details: Option<String>,
+    created_at: String,
 }
 
 #[derive(Debug)]
@@ -34,6 +53,78 @@ struct ReportOutput {
     total_items: usize,
 }
 
+#[derive(Debug, Default)]
+struct ReportFilters {
+    from_date: Option<String>,
+    to_date: Option<String>,
+    categories: Option<HashSet<String>>,
+}
+
+impl ReportFilters {
+    fn is_empty(&self) -> bool {
+        self.from_date.is_none() && self.to_date.is_none() && self.categories.is_none()
+    }
+
+    fn matches(&self, entry: &DailyEntry) -> bool {
+        if !within_range(&entry.date, &self.from_date, &self.to_date) {
+            return false;
+        }
+
+        self.categories
+            .as_ref()
+            .is_none_or(|categories| categories.contains(&entry.category_id))
+    }
+}
+
+#[derive(Debug, Default)]
+struct CommandFlags {
+    values: HashMap<String, String>,
+    switches: HashSet<String>,
+}
+
+impl CommandFlags {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut flags = Self::default();
+        let mut index = 0;
+
+        while index < args.len() {
+            let name = args[index]
+                .strip_prefix("--")
+                .filter(|name| !name.is_empty())
+                .ok_or_else(|| format!("unexpected argument: {}", args[index]))?;
+
+            match args.get(index + 1) {
+                Some(value) if !value.starts_with("--") => {
+                    flags.values.insert(name.to_string(), value.clone());
+                    index += 2;
+                }
+                _ => {
+                    flags.switches.insert(name.to_string());
+                    index += 1;
+                }
+            }
+        }
+
+        Ok(flags)
+    }
+
+    fn value(&self, name: &str) -> Option<&str> {
+        self.values
+            .get(name)
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    }
+
+    fn required(&self, name: &str) -> Result<&str, String> {
+        self.value(name)
+            .ok_or_else(|| format!("--{name} <value> is required"))
+    }
+
+    fn switch(&self, name: &str) -> bool {
+        self.switches.contains(name)
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 enum Key {
     Up,
@@ -42,6 +133,9 @@ enum Key {
     Right,
     Enter,
     Space,
+    PageUp,
+    PageDown,
+    Slash,
     Quit,
     Unknown,
 }
@@ -54,7 +148,17 @@ enum MenuResult {
 }
 
 struct RawMode {
-    original_state: String,
+    original_state: TerminalState,
+}
+
+#[cfg(not(windows))]
+type TerminalState = String;
+
+#[cfg(windows)]
+#[derive(Debug, Clone, Copy)]
+struct TerminalState {
+    input_mode: u32,
+    output_mode: u32,
 }
 
 impl RawMode {
@@ -63,30 +167,7 @@ impl RawMode {
             return Err("interactive terminal required (stdin is not a TTY)".to_string());
         }
 
-        let state = Command::new("stty")
-            .arg("-g")
-            .stdin(Stdio::inherit())
-            .output()
-            .map_err(|error| format!("failed to read terminal state: {error}"))?;
-
-        if !state.status.success() {
-            return Err("failed to read terminal state with stty -g".to_string());
-        }
-
-        let original_state = String::from_utf8(state.stdout)
-            .map_err(|error| format!("invalid terminal state bytes: {error}"))?
-            .trim()
-            .to_string();
-
-        let status = Command::new("stty")
-            .args(["raw", "-echo"])
-            .stdin(Stdio::inherit())
-            .status()
-            .map_err(|error| format!("failed to enable raw mode: {error}"))?;
-
-        if !status.success() {
-            return Err("failed to enable raw mode with stty raw -echo".to_string());
-        }
+        let original_state = enable_raw_mode()?;
 
         print!("\x1b[?25l");
         flush_stdout();
@@ -97,15 +178,118 @@ impl RawMode {
 
 impl Drop for RawMode {
     fn drop(&mut self) {
-        let _ = Command::new("stty")
-            .arg(self.original_state.trim())
-            .stdin(Stdio::inherit())
-            .status();
+        restore_terminal(&self.original_state);
         print!("\x1b[0m\x1b[?25h\n");
         flush_stdout();
     }
 }
 
+#[cfg(not(windows))]
+fn enable_raw_mode() -> Result<TerminalState, String> {
+    let state = Command::new("stty")
+        .arg("-g")
+        .stdin(Stdio::inherit())
+        .output()
+        .map_err(|error| format!("failed to read terminal state: {error}"))?;
+
+    if !state.status.success() {
+        return Err("failed to read terminal state with stty -g".to_string());
+    }
+
+    let original_state = String::from_utf8(state.stdout)
+        .map_err(|error| format!("invalid terminal state bytes: {error}"))?
+        .trim()
+        .to_string();
+
+    let status = Command::new("stty")
+        .args(["raw", "-echo"])
+        .stdin(Stdio::inherit())
+        .status()
+        .map_err(|error| format!("failed to enable raw mode: {error}"))?;
+
+    if !status.success() {
+        return Err("failed to enable raw mode with stty raw -echo".to_string());
+    }
+
+    Ok(original_state)
+}
+
+#[cfg(not(windows))]
+fn restore_terminal(state: &TerminalState) {
+    let _ = Command::new("stty")
+        .arg(state.trim())
+        .stdin(Stdio::inherit())
+        .status();
+}
+
+#[cfg(windows)]
+mod console {
+    use std::ffi::c_void;
+
+    pub type Handle = *mut c_void;
+
+    pub const STD_INPUT_HANDLE: u32 = -10i32 as u32;
+    pub const STD_OUTPUT_HANDLE: u32 = -11i32 as u32;
+
+    pub const ENABLE_PROCESSED_INPUT: u32 = 0x0001;
+    pub const ENABLE_LINE_INPUT: u32 = 0x0002;
+    pub const ENABLE_ECHO_INPUT: u32 = 0x0004;
+    pub const ENABLE_VIRTUAL_TERMINAL_INPUT: u32 = 0x0200;
+    pub const ENABLE_PROCESSED_OUTPUT: u32 = 0x0001;
+    pub const ENABLE_VIRTUAL_TERMINAL_PROCESSING: u32 = 0x0004;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        pub fn GetStdHandle(std_handle: u32) -> Handle;
+        pub fn GetConsoleMode(handle: Handle, mode: *mut u32) -> i32;
+        pub fn SetConsoleMode(handle: Handle, mode: u32) -> i32;
+    }
+
+    pub fn get_mode(std_handle: u32) -> Option<u32> {
+        let mut mode = 0u32;
+        let ok = unsafe { GetConsoleMode(GetStdHandle(std_handle), &mut mode) };
+        (ok != 0).then_some(mode)
+    }
+
+    pub fn set_mode(std_handle: u32, mode: u32) -> bool {
+        unsafe { SetConsoleMode(GetStdHandle(std_handle), mode) != 0 }
+    }
+}
+
+#[cfg(windows)]
+fn enable_raw_mode() -> Result<TerminalState, String> {
+    use console::*;
+
+    let input_mode = get_mode(STD_INPUT_HANDLE)
+        .ok_or_else(|| "failed to read console input mode".to_string())?;
+    let output_mode = get_mode(STD_OUTPUT_HANDLE)
+        .ok_or_else(|| "failed to read console output mode".to_string())?;
+
+    let raw_input = (input_mode
+        & !(ENABLE_LINE_INPUT | ENABLE_ECHO_INPUT | ENABLE_PROCESSED_INPUT))
+        | ENABLE_VIRTUAL_TERMINAL_INPUT;
+    if !set_mode(STD_INPUT_HANDLE, raw_input) {
+        return Err("failed to enable raw mode with SetConsoleMode".to_string());
+    }
+
+    let vt_output = output_mode | ENABLE_PROCESSED_OUTPUT | ENABLE_VIRTUAL_TERMINAL_PROCESSING;
+    if !set_mode(STD_OUTPUT_HANDLE, vt_output) {
+        set_mode(STD_INPUT_HANDLE, input_mode);
+        return Err("failed to enable ANSI output with SetConsoleMode".to_string());
+    }
+
+    Ok(TerminalState {
+        input_mode,
+        output_mode,
+    })
+}
+
+#[cfg(windows)]
+fn restore_terminal(state: &TerminalState) {
+    console::set_mode(console::STD_INPUT_HANDLE, state.input_mode);
+    console::set_mode(console::STD_OUTPUT_HANDLE, state.output_mode);
+}
+
 fn main() {
     if let Err(error) = run() {
         eprintln!("Error: {error}");
@@ -114,20 +298,269 @@ fn main() {
 }
 
 fn run() -> Result<(), String> {
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+    let db_flag = take_db_flag(&mut args)?;
+    let db_path = resolve_db_path(db_flag)?;
+
+    if let Some((command, rest)) = args.split_first() {
+        return run_command(command, rest, &db_path);
+    }
+
     let raw_mode = RawMode::new()?;
-    let conn = open_db()?;
+    let conn = open_db(&db_path)?;
 
-    let result = run_app(&conn);
+    let result = run_app(&conn, &db_path);
     drop(raw_mode);
 
     result
 }
 
-fn run_app(conn: &Connection) -> Result<(), String> {
+fn take_db_flag(args: &mut Vec<String>) -> Result<Option<PathBuf>, String> {
+    if args.first().map(String::as_str) != Some("--db") {
+        return Ok(None);
+    }
+
+    let path = args
+        .get(1)
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty() && !value.starts_with("--"))
+        .map(PathBuf::from)
+        .ok_or_else(|| "--db <path> requires a database path".to_string())?;
+
+    args.drain(..2);
+    Ok(Some(path))
+}
+
+fn run_command(command: &str, args: &[String], db_path: &Path) -> Result<(), String> {
+    let flags = CommandFlags::parse(args)?;
+
+    match command {
+        "report" => report_command(&flags, db_path),
+        "add" => add_command(&flags, db_path),
+        "list" => list_command(&flags, db_path),
+        "stats" => stats_command(&flags, db_path),
+        _ => Err(format!(
+            "unknown command: {command} (expected: report, add, list, stats)"
+        )),
+    }
+}
+
+fn report_command(flags: &CommandFlags, db_path: &Path) -> Result<(), String> {
+    let sprint_ref = flags.required("sprint")?;
+    match flags
+        .value("format")
+        .map(|value| value.to_ascii_lowercase())
+    {
+        None => {}
+        Some(format) if format == "markdown" || format == "md" => {}
+        Some(format) => return Err(format!("unsupported report format: {format}")),
+    }
+
+    let from_date = flags
+        .value("from")
+        .map(|value| parse_flag_date("from", value))
+        .transpose()?;
+    let to_date = flags
+        .value("to")
+        .map(|value| parse_flag_date("to", value))
+        .transpose()?;
+    if let (Some(from), Some(to)) = (&from_date, &to_date) {
+        if from > to {
+            return Err("--from must be on or before --to".to_string());
+        }
+    }
+
+    let categories = flags.value("category").map(|value| {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(str::to_string)
+            .collect::<HashSet<_>>()
+    });
+
+    let conn = open_db(db_path)?;
+    let sprint =
+        find_sprint(&conn, sprint_ref)?.ok_or_else(|| format!("sprint not found: {sprint_ref}"))?;
+
+    if let Some(categories) = &categories {
+        for category_id in categories {
+            if !category_exists(&conn, category_id)? {
+                return Err(format!("category not found: {category_id}"));
+            }
+        }
+    }
+
+    let filters = ReportFilters {
+        from_date,
+        to_date,
+        categories: categories.filter(|categories| !categories.is_empty()),
+    };
+    let output = generate_report(&conn, &sprint, &filters)?;
+    println!("{}", output.file_path);
+
+    Ok(())
+}
+
+fn parse_flag_date(name: &str, value: &str) -> Result<String, String> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map(|date| date.format("%Y-%m-%d").to_string())
+        .map_err(|_| format!("--{name} must be in YYYY-MM-DD format"))
+}
+
+fn add_command(flags: &CommandFlags, db_path: &Path) -> Result<(), String> {
+    let sprint_ref = flags.required("sprint")?;
+    let category_id = flags.required("category")?;
+    let title = flags.required("title")?.trim();
+    if title.is_empty() {
+        return Err("title is required".to_string());
+    }
+
+    let date = match flags.value("date") {
+        Some(value) => parse_flag_date("date", value)?,
+        None => Local::now().date_naive().format("%Y-%m-%d").to_string(),
+    };
+    let details = flags
+        .value("details")
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| value.to_string());
+
+    let conn = open_db(db_path)?;
+    validate_title_length(&conn, title)?;
+    let sprint =
+        find_sprint(&conn, sprint_ref)?.ok_or_else(|| format!("sprint not found: {sprint_ref}"))?;
+
+    if !category_exists(&conn, category_id)? {
+        return Err(format!("category not found: {category_id}"));
+    }
+
+    let id = next_id("entry");
+    conn.execute(
+        "INSERT INTO entries (id, sprint_id, date, category_id, title, details, created_at, updated_at, seq, order_index)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7,
+                 (SELECT COALESCE(MAX(seq), 0) + 1 FROM entries WHERE sprint_id = ?2),
+                 (SELECT COALESCE(MAX(order_index) + 1, 0) FROM entries WHERE sprint_id = ?2 AND date = ?3 AND category_id = ?4))",
+        params![id, sprint.id, date, category_id, title, details, now()],
+    )
+    .map_err(|error| format!("failed to add entry: {error}"))?;
+
+    println!("{id}");
+    Ok(())
+}
+
+fn list_command(flags: &CommandFlags, db_path: &Path) -> Result<(), String> {
+    let conn = open_db(db_path)?;
+    let sprints = list_sprints(&conn)?;
+
+    if flags.switch("json") {
+        let items = sprints
+            .iter()
+            .map(|sprint| {
+                serde_json::json!({
+                    "id": sprint.id,
+                    "code": sprint.code,
+                    "name": sprint.name,
+                    "start_date": sprint.start_date,
+                    "end_date": sprint.end_date,
+                    "entry_count": sprint.entry_count,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let json = serde_json::to_string_pretty(&items)
+            .map_err(|error| format!("failed to serialize sprints: {error}"))?;
+        println!("{json}");
+        return Ok(());
+    }
+
+    for sprint in sprints {
+        println!(
+            "{}\t{}\t{}\t{}\t{}",
+            sprint.code,
+            sprint.name,
+            sprint.start_date,
+            sprint.end_date.unwrap_or_default(),
+            sprint.entry_count
+        );
+    }
+
+    Ok(())
+}
+
+fn stats_command(flags: &CommandFlags, db_path: &Path) -> Result<(), String> {
+    let conn = open_db(db_path)?;
+    let sprint = match flags.value("sprint") {
+        Some(sprint_ref) => Some(
+            find_sprint(&conn, sprint_ref)?
+                .ok_or_else(|| format!("sprint not found: {sprint_ref}"))?,
+        ),
+        None => None,
+    };
+    let sprint_id = sprint.as_ref().map(|sprint| sprint.id.as_str());
+
+    let (entry_count, distinct_days) = conn
+        .query_row(
+            "SELECT COUNT(*), COUNT(DISTINCT date)
+             FROM entries
+             WHERE ?1 IS NULL OR sprint_id = ?1",
+            params![sprint_id],
+            |row| Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?)),
+        )
+        .map_err(|error| format!("failed to count entries: {error}"))?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT COALESCE(c.name, e.category_id), COUNT(*)
+             FROM entries e
+             LEFT JOIN categories c ON c.id = e.category_id
+             WHERE ?1 IS NULL OR e.sprint_id = ?1
+             GROUP BY e.category_id
+             ORDER BY COUNT(*) DESC, COALESCE(c.name, e.category_id) COLLATE NOCASE ASC",
+        )
+        .map_err(|error| format!("failed to prepare category stats query: {error}"))?;
+
+    let by_category = stmt
+        .query_map(params![sprint_id], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+        })
+        .map_err(|error| format!("failed to query category stats: {error}"))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to read category stats: {error}"))?;
+
+    if flags.switch("json") {
+        let categories = by_category
+            .iter()
+            .map(|(name, count)| serde_json::json!({ "name": name, "entry_count": count }))
+            .collect::<Vec<_>>();
+
+        let stats = serde_json::json!({
+            "sprint": sprint.as_ref().map(|sprint| sprint.code.as_str()),
+            "entry_count": entry_count,
+            "distinct_days": distinct_days,
+            "categories": categories,
+        });
+
+        let json = serde_json::to_string_pretty(&stats)
+            .map_err(|error| format!("failed to serialize stats: {error}"))?;
+        println!("{json}");
+        return Ok(());
+    }
+
+    println!("entries\t{entry_count}");
+    println!("days\t{distinct_days}");
+    for (name, count) in by_category {
+        println!("category:{name}\t{count}");
+    }
+
+    Ok(())
+}
+
+fn run_app(conn: &Connection, db_path: &Path) -> Result<(), String> {
     loop {
         let subtitle = vec![
             "Sprint explorer and report generator".to_string(),
-            format!("Database: {}", resolve_db_path()?.display()),
+            format!("Database: {}", db_path.display()),
         ];
 
         let options = vec!["Sprints".to_string(), "Exit".to_string()];
@@ -160,9 +593,16 @@ fn sprints_flow(conn: &Connection) -> Result<bool, String> {
             }
         }
 
+        let active_id = pick_active_sprint_id(conn)?;
         let mut options = sprints
             .iter()
-            .map(|sprint| sprint_label(sprint))
+            .map(|sprint| {
+                if active_id.as_deref() == Some(sprint.id.as_str()) {
+                    format!("{} (active)", sprint_label(sprint))
+                } else {
+                    sprint_label(sprint)
+                }
+            })
             .collect::<Vec<_>>();
         options.push("Back".to_string());
 
@@ -184,6 +624,8 @@ fn sprint_flow(conn: &Connection, sprint: &Sprint) -> Result<bool, String> {
     loop {
         let entries = list_entries_for_sprint(conn, &sprint.id)?;
         let categories = list_categories_map(conn)?;
+        let colors = list_category_colors(conn)?;
+        let no_colors = HashMap::new();
 
         let subtitle = vec![
             sprint_label(sprint),
@@ -203,6 +645,7 @@ fn sprint_flow(conn: &Connection, sprint: &Sprint) -> Result<bool, String> {
             "See specific date".to_string(),
             "See all details".to_string(),
             "Copy one day data".to_string(),
+            "Copy all details".to_string(),
             "Generate report".to_string(),
             "Back".to_string(),
         ];
@@ -217,8 +660,8 @@ fn sprint_flow(conn: &Connection, sprint: &Sprint) -> Result<bool, String> {
             }
             MenuResult::Selected(1) => match pick_date(&entries)? {
                 DatePick::Date(date) => {
-                    let text = build_day_text(&date, &entries, &categories);
-                    let lines = split_and_truncate(&text, DEFAULT_TRUNCATE_LINES);
+                    let text = build_day_text(&date, &entries, &categories, &colors);
+                    let lines = text.lines().map(str::to_string).collect::<Vec<_>>();
                     match text_screen(&format!("Date {date}"), &lines)? {
                         MenuResult::Quit => return Ok(false),
                         _ => {}
@@ -228,16 +671,13 @@ fn sprint_flow(conn: &Connection, sprint: &Sprint) -> Result<bool, String> {
                 DatePick::Quit => return Ok(false),
             },
             MenuResult::Selected(2) => {
-                let text = build_all_details_text(&entries, &categories);
-                let lines = split_and_truncate(&text, DEFAULT_TRUNCATE_LINES);
-                match text_screen("All Details", &lines)? {
-                    MenuResult::Quit => return Ok(false),
-                    _ => {}
+                if let MenuResult::Quit = all_details_screen(&entries, &categories, &colors)? {
+                    return Ok(false);
                 }
             }
             MenuResult::Selected(3) => match pick_date(&entries)? {
                 DatePick::Date(date) => {
-                    let text = build_day_text(&date, &entries, &categories);
+                    let text = build_day_text(&date, &entries, &categories, &no_colors);
                     let copy_result = copy_to_clipboard(&text);
                     let mut lines = Vec::new();
                     lines.push(format!("Date: {date}"));
@@ -260,7 +700,24 @@ fn sprint_flow(conn: &Connection, sprint: &Sprint) -> Result<bool, String> {
                 DatePick::Quit => return Ok(false),
             },
             MenuResult::Selected(4) => {
-                let output = generate_report(conn, sprint)?;
+                let text = build_all_details_text(&entries, &categories, &no_colors);
+                let mut lines = vec![format!("Sprint: {}", sprint_label(sprint))];
+                match copy_to_clipboard(&text) {
+                    Ok(()) => {
+                        lines.push(format!("Copied {} entries to clipboard.", entries.len()));
+                    }
+                    Err(error) => {
+                        lines.push(format!("Clipboard copy failed: {error}"));
+                        lines.push("Data preview:".to_string());
+                        lines.extend(split_and_truncate(&text, 15));
+                    }
+                }
+                if let MenuResult::Quit = text_screen("Copy All Details", &lines)? {
+                    return Ok(false);
+                }
+            }
+            MenuResult::Selected(5) => {
+                let output = generate_report(conn, sprint, &ReportFilters::default())?;
                 let lines = vec![
                     format!("Generated report for {}", sprint_label(sprint)),
                     format!("Included items: {}", output.total_items),
@@ -272,7 +729,7 @@ fn sprint_flow(conn: &Connection, sprint: &Sprint) -> Result<bool, String> {
                     _ => {}
                 }
             }
-            MenuResult::Selected(5) | MenuResult::Back => return Ok(true),
+            MenuResult::Selected(6) | MenuResult::Back => return Ok(true),
             MenuResult::Quit => return Ok(false),
             MenuResult::Selected(_) => {}
         }
@@ -325,13 +782,17 @@ fn menu_screen(title: &str, subtitle: &[String], options: &[String]) -> Result<M
     let mut selected = 0usize;
 
     loop {
+        let width = terminal_width();
+
         clear_screen();
         println!("DevLog Desk CLI");
         println!("{title}");
         println!();
 
         for line in subtitle {
-            println!("{line}");
+            for wrapped in wrap_line(line, width, &hanging_indent(line)) {
+                println!("{wrapped}");
+            }
         }
 
         if !subtitle.is_empty() {
@@ -339,15 +800,25 @@ fn menu_screen(title: &str, subtitle: &[String], options: &[String]) -> Result<M
         }
 
         for (index, option) in options.iter().enumerate() {
-            if index == selected {
-                println!("> {option}");
-            } else {
-                println!("  {option}");
+            let marker = if index == selected { "> " } else { "  " };
+            let wrapped = wrap_line(option, width.saturating_sub(2), &hanging_indent(option));
+            for (line_index, line) in wrapped.iter().enumerate() {
+                if line_index == 0 {
+                    println!("{marker}{line}");
+                } else {
+                    println!("  {line}");
+                }
             }
         }
 
         println!();
-        println!("Keys: Up/Down navigate, Space/Enter select, Left back, Q quit");
+        for line in wrap_line(
+            "Keys: Up/Down navigate, Space/Enter select, Left back, Q quit",
+            width,
+            "      ",
+        ) {
+            println!("{line}");
+        }
         flush_stdout();
 
         match read_key()? {
@@ -362,18 +833,290 @@ fn menu_screen(title: &str, subtitle: &[String], options: &[String]) -> Result<M
             Key::Enter | Key::Space => return Ok(MenuResult::Selected(selected)),
             Key::Left => return Ok(MenuResult::Back),
             Key::Quit => return Ok(MenuResult::Quit),
-            Key::Right | Key::Unknown => {}
+            Key::Right | Key::PageUp | Key::PageDown | Key::Slash | Key::Unknown => {}
         }
     }
 }
 
+fn stty_size() -> Option<(usize, usize)> {
+    let output = Command::new("stty")
+        .arg("size")
+        .stdin(Stdio::inherit())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+
+    let text = String::from_utf8_lossy(&output.stdout);
+    let mut parts = text
+        .split_whitespace()
+        .map(|value| value.parse::<usize>().ok());
+
+    match (parts.next()??, parts.next()??) {
+        (rows, cols) if rows > 0 && cols > 0 => Some((rows, cols)),
+        _ => None,
+    }
+}
+
+fn env_dimension(name: &str) -> Option<usize> {
+    env::var(name)
+        .ok()
+        .and_then(|value| value.trim().parse::<usize>().ok())
+        .filter(|value| *value > 0)
+}
+
+fn terminal_width() -> usize {
+    stty_size()
+        .map(|(_, cols)| cols)
+        .or_else(|| env_dimension("COLUMNS"))
+        .unwrap_or(DEFAULT_TERMINAL_WIDTH)
+}
+
+fn terminal_height() -> usize {
+    stty_size()
+        .map(|(rows, _)| rows)
+        .or_else(|| env_dimension("LINES"))
+        .unwrap_or(DEFAULT_TERMINAL_HEIGHT)
+}
+
+fn wrap_lines(lines: &[String], width: usize) -> Vec<String> {
+    lines
+        .iter()
+        .flat_map(|line| wrap_line(line, width, &hanging_indent(line)))
+        .collect()
+}
+
+fn scroll_offset(key: Key, offset: usize, page: usize, total: usize) -> usize {
+    let max_offset = total.saturating_sub(page);
+    let next = match key {
+        Key::Up => offset.saturating_sub(1),
+        Key::Down => offset + 1,
+        Key::PageUp => offset.saturating_sub(page),
+        Key::PageDown => offset + page,
+        _ => offset,
+    };
+
+    next.min(max_offset)
+}
+
+fn print_window(lines: &[String], offset: usize, page: usize) {
+    let end = (offset + page).min(lines.len());
+    for line in &lines[offset..end] {
+        println!("{line}");
+    }
+
+    if lines.len() > page {
+        println!();
+        println!("line {}\u{2013}{} of {}", offset + 1, end, lines.len());
+    }
+}
+
+fn hanging_indent(line: &str) -> String {
+    let leading = line.len() - line.trim_start().len();
+    let rest = &line[leading..];
+    let bullet = if rest.starts_with("- ") || rest.starts_with("> ") {
+        2
+    } else {
+        0
+    };
+
+    " ".repeat(leading + bullet)
+}
+
+fn wrap_line(line: &str, width: usize, indent: &str) -> Vec<String> {
+    let indent_width = indent.chars().count();
+    let width = width.max(indent_width + 1);
+
+    if line.chars().count() <= width {
+        return vec![line.to_string()];
+    }
+
+    let mut wrapped = Vec::new();
+    let mut rest = line;
+    let mut prefix = "";
+    let mut available = width;
+
+    loop {
+        let chars = rest.char_indices().collect::<Vec<_>>();
+        if chars.len() <= available {
+            wrapped.push(format!("{prefix}{rest}"));
+            break;
+        }
+
+        let limit = chars[available].0;
+        let split_at = rest[..=limit]
+            .rfind(' ')
+            .filter(|index| !rest[..*index].trim().is_empty());
+
+        let (chunk, remainder) = match split_at {
+            Some(index) => (&rest[..index], rest[index + 1..].trim_start()),
+            None => (&rest[..limit], &rest[limit..]),
+        };
+
+        wrapped.push(format!("{prefix}{}", chunk.trim_end()));
+        if remainder.is_empty() {
+            break;
+        }
+
+        rest = remainder;
+        prefix = indent;
+        available = width - indent_width;
+    }
+
+    wrapped
+}
+
 fn text_screen(title: &str, lines: &[String]) -> Result<MenuResult, String> {
-    let mut subtitle = Vec::new();
-    subtitle.extend_from_slice(lines);
-    subtitle.push(String::new());
-    subtitle.push("Press Space/Enter to go back.".to_string());
+    let mut offset = 0usize;
+
+    loop {
+        let width = terminal_width();
+        let page = terminal_height()
+            .saturating_sub(TEXT_SCREEN_RESERVED_LINES)
+            .max(1);
+        let wrapped = wrap_lines(lines, width);
+        offset = offset.min(wrapped.len().saturating_sub(page));
+
+        clear_screen();
+        println!("DevLog Desk CLI");
+        println!("{title}");
+        println!();
+
+        print_window(&wrapped, offset, page);
+
+        println!();
+        for line in wrap_line(
+            "Keys: Up/Down scroll, [/] or PgUp/PgDn page, Space/Enter/Left back, Q quit",
+            width,
+            "      ",
+        ) {
+            println!("{line}");
+        }
+        flush_stdout();
+
+        match read_key()? {
+            key @ (Key::Up | Key::Down | Key::PageUp | Key::PageDown) => {
+                offset = scroll_offset(key, offset, page, wrapped.len());
+            }
+            Key::Enter | Key::Space | Key::Left => return Ok(MenuResult::Back),
+            Key::Quit => return Ok(MenuResult::Quit),
+            Key::Right | Key::Slash | Key::Unknown => {}
+        }
+    }
+}
+
+fn all_details_screen(
+    entries: &[DailyEntry],
+    categories: &HashMap<String, String>,
+    colors: &HashMap<String, String>,
+) -> Result<MenuResult, String> {
+    let mut filter = String::new();
+    let mut offset = 0usize;
 
-    menu_screen(title, &subtitle, &["Back".to_string()])
+    loop {
+        let width = terminal_width();
+        let page = terminal_height()
+            .saturating_sub(ALL_DETAILS_RESERVED_LINES)
+            .max(1);
+
+        let needle = filter.to_lowercase();
+        let filtered = entries
+            .iter()
+            .filter(|entry| {
+                needle.is_empty()
+                    || entry.title.to_lowercase().contains(&needle)
+                    || entry
+                        .details
+                        .as_deref()
+                        .map(|details| details.to_lowercase().contains(&needle))
+                        .unwrap_or(false)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        clear_screen();
+        println!("DevLog Desk CLI");
+        println!("All Details");
+        println!();
+
+        if filter.is_empty() {
+            println!("Press / to filter by title or details.");
+        } else {
+            println!(
+                "Filter: \"{filter}\" ({} of {} entries match)",
+                filtered.len(),
+                entries.len()
+            );
+        }
+        println!();
+
+        let body = if filtered.is_empty() && !filter.is_empty() {
+            vec!["No entries match the filter.".to_string()]
+        } else {
+            let text = build_all_details_text(&filtered, categories, colors);
+            let lines = text.lines().map(str::to_string).collect::<Vec<_>>();
+            wrap_lines(&lines, width)
+        };
+        offset = offset.min(body.len().saturating_sub(page));
+        print_window(&body, offset, page);
+
+        println!();
+        for line in wrap_line(
+            "Keys: / filter (empty clears), Up/Down scroll, [/] or PgUp/PgDn page, Space/Enter/Left back, Q quit",
+            width,
+            "      ",
+        ) {
+            println!("{line}");
+        }
+        flush_stdout();
+
+        match read_key()? {
+            key @ (Key::Up | Key::Down | Key::PageUp | Key::PageDown) => {
+                offset = scroll_offset(key, offset, page, body.len());
+            }
+            Key::Slash => {
+                if let Some(value) = read_prompt_line("Filter: ")? {
+                    filter = value.trim().to_string();
+                    offset = 0;
+                }
+            }
+            Key::Enter | Key::Space | Key::Left => return Ok(MenuResult::Back),
+            Key::Quit => return Ok(MenuResult::Quit),
+            Key::Right | Key::Unknown => {}
+        }
+    }
+}
+
+fn read_prompt_line(prompt: &str) -> Result<Option<String>, String> {
+    print!("\r\n{prompt}\x1b[?25h");
+    flush_stdout();
+
+    let mut value = String::new();
+    let result = loop {
+        let mut byte = [0u8; 1];
+        io::stdin()
+            .read_exact(&mut byte)
+            .map_err(|error| format!("failed to read input: {error}"))?;
+
+        match byte[0] {
+            b'\r' | b'\n' => break Some(value),
+            b'\x1b' => break None,
+            b'\x7f' | b'\x08' if !value.is_empty() => {
+                value.pop();
+                print!("\x08 \x08");
+                flush_stdout();
+            }
+            ch if ch.is_ascii() && !ch.is_ascii_control() => {
+                value.push(ch as char);
+                print!("{}", ch as char);
+                flush_stdout();
+            }
+            _ => {}
+        }
+    };
+
+    print!("\x1b[?25l");
+    flush_stdout();
+    Ok(result)
 }
 
 fn read_key() -> Result<Key, String> {
@@ -385,7 +1128,27 @@ fn read_key() -> Result<Key, String> {
     match first[0] {
         b'\r' | b'\n' => Ok(Key::Enter),
         b' ' => Ok(Key::Space),
+        b'/' => Ok(Key::Slash),
+        b'[' => Ok(Key::PageUp),
+        b']' => Ok(Key::PageDown),
         b'q' | b'Q' => Ok(Key::Quit),
+        #[cfg(windows)]
+        0x00 | 0xe0 => {
+            let mut code = [0u8; 1];
+            if io::stdin().read_exact(&mut code).is_err() {
+                return Ok(Key::Unknown);
+            }
+
+            match code[0] {
+                b'H' => Ok(Key::Up),
+                b'P' => Ok(Key::Down),
+                b'M' => Ok(Key::Right),
+                b'K' => Ok(Key::Left),
+                b'I' => Ok(Key::PageUp),
+                b'Q' => Ok(Key::PageDown),
+                _ => Ok(Key::Unknown),
+            }
+        }
         b'\x1b' => {
             let mut seq = [0u8; 2];
             if io::stdin().read_exact(&mut seq).is_err() {
@@ -398,6 +1161,18 @@ fn read_key() -> Result<Key, String> {
                     b'B' => Ok(Key::Down),
                     b'C' => Ok(Key::Right),
                     b'D' => Ok(Key::Left),
+                    b'5' | b'6' => {
+                        let mut tail = [0u8; 1];
+                        if io::stdin().read_exact(&mut tail).is_err() || tail[0] != b'~' {
+                            return Ok(Key::Unknown);
+                        }
+
+                        if seq[1] == b'5' {
+                            Ok(Key::PageUp)
+                        } else {
+                            Ok(Key::PageDown)
+                        }
+                    }
                     _ => Ok(Key::Unknown),
                 }
             } else {
@@ -416,7 +1191,13 @@ fn flush_stdout() {
     let _ = io::stdout().flush();
 }
 
-fn resolve_db_path() -> Result<PathBuf, String> {
+/// Resolves the database file, in order of precedence: the `--db` flag,
+/// `DEVLOG_DB_PATH`, then `DB_FILE_NAME` inside [`app_data_root`].
+fn resolve_db_path(db_flag: Option<PathBuf>) -> Result<PathBuf, String> {
+    if let Some(path) = db_flag {
+        return Ok(path);
+    }
+
     if let Ok(explicit_path) = env::var("DEVLOG_DB_PATH") {
         let trimmed = explicit_path.trim();
         if !trimmed.is_empty() {
@@ -429,6 +1210,17 @@ fn resolve_db_path() -> Result<PathBuf, String> {
     Ok(root)
 }
 
+fn app_identifier() -> String {
+    env::var("DEVLOG_APP_IDENTIFIER")
+        .ok()
+        .map(|identifier| identifier.trim().to_string())
+        .filter(|identifier| !identifier.is_empty())
+        .unwrap_or_else(|| APP_IDENTIFIER.to_string())
+}
+
+/// Resolves the data directory: `DEVLOG_DATA_DIR` if set, otherwise the
+/// platform data directory for `DEVLOG_APP_IDENTIFIER` (falling back to
+/// `APP_IDENTIFIER`).
 fn app_data_root() -> Result<PathBuf, String> {
     if let Ok(explicit_root) = env::var("DEVLOG_DATA_DIR") {
         let trimmed = explicit_root.trim();
@@ -437,6 +1229,8 @@ fn app_data_root() -> Result<PathBuf, String> {
         }
     }
 
+    let identifier = app_identifier();
+
     #[cfg(target_os = "macos")]
     {
         let home =
@@ -444,14 +1238,14 @@ fn app_data_root() -> Result<PathBuf, String> {
         return Ok(PathBuf::from(home)
             .join("Library")
             .join("Application Support")
-            .join(APP_IDENTIFIER));
+            .join(&identifier));
     }
 
     #[cfg(target_os = "windows")]
     {
         let appdata = env::var("APPDATA")
             .map_err(|_| "APPDATA environment variable is missing".to_string())?;
-        return Ok(PathBuf::from(appdata).join(APP_IDENTIFIER));
+        return Ok(PathBuf::from(appdata).join(&identifier));
     }
 
     #[cfg(not(any(target_os = "macos", target_os = "windows")))]
@@ -459,7 +1253,7 @@ fn app_data_root() -> Result<PathBuf, String> {
         if let Ok(xdg_data_home) = env::var("XDG_DATA_HOME") {
             let trimmed = xdg_data_home.trim();
             if !trimmed.is_empty() {
-                return Ok(PathBuf::from(trimmed).join(APP_IDENTIFIER));
+                return Ok(PathBuf::from(trimmed).join(&identifier));
             }
         }
 
@@ -468,13 +1262,29 @@ fn app_data_root() -> Result<PathBuf, String> {
         Ok(PathBuf::from(home)
             .join(".local")
             .join("share")
-            .join(APP_IDENTIFIER))
+            .join(&identifier))
     }
 }
 
-fn reports_dir() -> Result<PathBuf, String> {
-    let mut root = app_data_root()?;
-    root.push("reports");
+fn reports_dir(conn: &Connection) -> Result<PathBuf, String> {
+    let custom = conn
+        .query_row(
+            "SELECT value FROM settings WHERE key = ?1",
+            params![REPORT_DIRECTORY_SETTING],
+            |row| row.get::<_, String>(0),
+        )
+        .optional()
+        .map_err(|error| format!("failed to read report directory setting: {error}"))?;
+
+    let root = match custom {
+        Some(path) => PathBuf::from(path),
+        None => {
+            let mut root = app_data_root()?;
+            root.push("reports");
+            root
+        }
+    };
+
     fs::create_dir_all(&root).map_err(|error| {
         format!(
             "unable to create reports directory {}: {error}",
@@ -484,9 +1294,7 @@ fn reports_dir() -> Result<PathBuf, String> {
     Ok(root)
 }
 
-fn open_db() -> Result<Connection, String> {
-    let db_path = resolve_db_path()?;
-
+fn open_db(db_path: &Path) -> Result<Connection, String> {
     if let Some(parent) = db_path.parent() {
         fs::create_dir_all(parent).map_err(|error| {
             format!(
@@ -496,7 +1304,7 @@ fn open_db() -> Result<Connection, String> {
         })?;
     }
 
-    let conn = Connection::open(&db_path)
+    let conn = Connection::open(db_path)
         .map_err(|error| format!("unable to open database {}: {error}", db_path.display()))?;
 
     init_schema(&conn)?;
@@ -513,7 +1321,8 @@ fn init_schema(conn: &Connection) -> Result<(), String> {
         CREATE TABLE IF NOT EXISTS categories (
             id TEXT PRIMARY KEY,
             name TEXT NOT NULL UNIQUE COLLATE NOCASE,
-            created_at TEXT NOT NULL
+            created_at TEXT NOT NULL,
+            color TEXT
         );
 
         CREATE TABLE IF NOT EXISTS sprints (
@@ -522,7 +1331,9 @@ fn init_schema(conn: &Connection) -> Result<(), String> {
             name TEXT NOT NULL,
             start_date TEXT NOT NULL,
             end_date TEXT,
-            created_at TEXT NOT NULL
+            created_at TEXT NOT NULL,
+            archived INTEGER NOT NULL DEFAULT 0,
+            description TEXT
         );
 
         CREATE TABLE IF NOT EXISTS entries (
@@ -539,9 +1350,51 @@ fn init_schema(conn: &Connection) -> Result<(), String> {
 
         CREATE INDEX IF NOT EXISTS idx_entries_sprint_date
             ON entries (sprint_id, date, category_id, created_at);
+
+        CREATE TABLE IF NOT EXISTS settings (
+            key TEXT PRIMARY KEY,
+            value TEXT NOT NULL
+        );
         ",
     )
-    .map_err(|error| format!("failed to initialize database schema: {error}"))
+    .map_err(|error| format!("failed to initialize database schema: {error}"))?;
+
+    ensure_column(conn, "categories", "color", "TEXT")?;
+    ensure_column(conn, "sprints", "archived", "INTEGER NOT NULL DEFAULT 0")?;
+    ensure_column(conn, "sprints", "description", "TEXT")?;
+    ensure_column(conn, "entries", "updated_at", "TEXT NOT NULL DEFAULT ''")?;
+    ensure_column(conn, "entries", "seq", "INTEGER NOT NULL DEFAULT 0")?;
+    ensure_column(conn, "entries", "order_index", "INTEGER NOT NULL DEFAULT 0")
+}
+
+fn column_exists(conn: &Connection, table: &str, column: &str) -> Result<bool, String> {
+    let mut stmt = conn
+        .prepare(&format!("PRAGMA table_info({table})"))
+        .map_err(|error| format!("failed to inspect {table} columns: {error}"))?;
+
+    let names = stmt
+        .query_map([], |row| row.get::<_, String>(1))
+        .map_err(|error| format!("failed to read {table} columns: {error}"))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect {table} columns: {error}"))?;
+
+    Ok(names.iter().any(|name| name.eq_ignore_ascii_case(column)))
+}
+
+fn ensure_column(
+    conn: &Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<(), String> {
+    if column_exists(conn, table, column)? {
+        return Ok(());
+    }
+
+    conn.execute_batch(&format!(
+        "ALTER TABLE {table} ADD COLUMN {column} {definition};"
+    ))
+    .map_err(|error| format!("failed to add {table}.{column} column: {error}"))
 }
 
 fn ensure_default_categories_db(conn: &Connection) -> Result<(), String> {
@@ -554,13 +1407,10 @@ fn ensure_default_categories_db(conn: &Connection) -> Result<(), String> {
     }
 
     let created_at = now();
-    let defaults = vec![
-        ("pr-reviews", "PR-Reviews"),
-        ("meeting", "Meeting"),
-        ("tasks", "Tasks"),
-    ];
+    let mut taken_ids = HashSet::new();
 
-    for (id, name) in defaults {
+    for name in defaults::default_category_names() {
+        let id = defaults::unique_category_id(&slugify(&name), &mut taken_ids);
         conn.execute(
             "INSERT INTO categories (id, name, created_at) VALUES (?1, ?2, ?3)",
             params![id, name, created_at],
@@ -574,9 +1424,11 @@ fn ensure_default_categories_db(conn: &Connection) -> Result<(), String> {
 fn list_sprints(conn: &Connection) -> Result<Vec<Sprint>, String> {
     let mut stmt = conn
         .prepare(
-            "SELECT id, code, name, start_date, end_date
-             FROM sprints
-             ORDER BY start_date DESC, created_at DESC",
+            "SELECT s.id, s.code, s.name, s.start_date, s.end_date, s.description, COUNT(e.id)
+             FROM sprints s
+             LEFT JOIN entries e ON e.sprint_id = s.id
+             GROUP BY s.id
+             ORDER BY s.start_date DESC, s.created_at DESC",
         )
         .map_err(|error| format!("failed to prepare sprints query: {error}"))?;
 
@@ -588,6 +1440,8 @@ fn list_sprints(conn: &Connection) -> Result<Vec<Sprint>, String> {
                 name: row.get(2)?,
                 start_date: row.get(3)?,
                 end_date: row.get(4)?,
+                description: row.get(5)?,
+                entry_count: row.get(6)?,
             })
         })
         .map_err(|error| format!("failed to query sprints: {error}"))?;
@@ -596,13 +1450,50 @@ fn list_sprints(conn: &Connection) -> Result<Vec<Sprint>, String> {
         .map_err(|error| format!("failed to collect sprints: {error}"))
 }
 
+fn find_sprint(conn: &Connection, code_or_id: &str) -> Result<Option<Sprint>, String> {
+    conn.query_row(
+        "SELECT id, code, name, start_date, end_date, description,
+                (SELECT COUNT(*) FROM entries WHERE entries.sprint_id = sprints.id)
+         FROM sprints
+         WHERE id = ?1 OR lower(code) = lower(?1)
+         LIMIT 1",
+        params![code_or_id],
+        |row| {
+            Ok(Sprint {
+                id: row.get(0)?,
+                code: row.get(1)?,
+                name: row.get(2)?,
+                start_date: row.get(3)?,
+                end_date: row.get(4)?,
+                description: row.get(5)?,
+                entry_count: row.get(6)?,
+            })
+        },
+    )
+    .optional()
+    .map_err(|error| format!("failed to look up sprint {code_or_id}: {error}"))
+}
+
+fn category_exists(conn: &Connection, id: &str) -> Result<bool, String> {
+    let existing = conn
+        .query_row(
+            "SELECT 1 FROM categories WHERE id = ?1 LIMIT 1",
+            params![id],
+            |row| row.get::<_, i64>(0),
+        )
+        .optional()
+        .map_err(|error| format!("failed to check category existence: {error}"))?;
+
+    Ok(existing.is_some())
+}
+
 fn list_entries_for_sprint(conn: &Connection, sprint_id: &str) -> Result<Vec<DailyEntry>, String> {
     let mut stmt = conn
         .prepare(
-            "SELECT date, category_id, title, details
+            "SELECT date, category_id, title, details, created_at
              FROM entries
              WHERE sprint_id = ?1
-             ORDER BY date, category_id, created_at",
+             ORDER BY date, category_id, order_index, created_at",
         )
         .map_err(|error| format!("failed to prepare entries query: {error}"))?;
 
@@ -613,6 +1504,7 @@ fn list_entries_for_sprint(conn: &Connection, sprint_id: &str) -> Result<Vec<Dai
                 category_id: row.get(1)?,
                 title: row.get(2)?,
                 details: row.get(3)?,
+                created_at: row.get(4)?,
             })
         })
         .map_err(|error| format!("failed to query entries: {error}"))?;
@@ -636,9 +1528,60 @@ fn list_categories_map(conn: &Connection) -> Result<HashMap<String, String>, Str
         .map_err(|error| format!("failed to collect categories: {error}"))
 }
 
+fn list_category_colors(conn: &Connection) -> Result<HashMap<String, String>, String> {
+    if !color_enabled() {
+        return Ok(HashMap::new());
+    }
+
+    let mut stmt = conn
+        .prepare("SELECT id, color FROM categories WHERE color IS NOT NULL")
+        .map_err(|error| format!("failed to prepare category colors query: {error}"))?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })
+        .map_err(|error| format!("failed to query category colors: {error}"))?;
+
+    let mut colors = HashMap::new();
+    for row in rows {
+        let (id, color) = row.map_err(|error| format!("failed to read category color: {error}"))?;
+        if let Some((red, green, blue)) = parse_hex_color(&color) {
+            colors.insert(id, format!("\x1b[38;2;{red};{green};{blue}m"));
+        }
+    }
+
+    Ok(colors)
+}
+
+fn color_enabled() -> bool {
+    let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    !no_color && io::stdout().is_terminal()
+}
+
+fn parse_hex_color(raw: &str) -> Option<(u8, u8, u8)> {
+    let hex = raw.trim().strip_prefix('#')?;
+    if hex.len() != 6 || !hex.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channel = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).ok();
+    Some((channel(0)?, channel(2)?, channel(4)?))
+}
+
+fn paint_category(label: &str, category_id: &str, colors: &HashMap<String, String>) -> String {
+    match colors.get(category_id) {
+        Some(color) => format!("{color}{label}\x1b[0m"),
+        None => label.to_string(),
+    }
+}
+
 fn sprint_summary_lines(sprint: &Sprint, entries: &[DailyEntry]) -> Vec<String> {
-    let mut lines = vec![
-        format!("Sprint: {}", sprint_label(sprint)),
+    let mut lines = vec![format!("Sprint: {}", sprint_label(sprint))];
+    if let Some(description) = &sprint.description {
+        lines.push(format!("Goal: {description}"));
+    }
+    lines.extend([
         format!(
             "Window: {} to {}",
             sprint.start_date,
@@ -650,7 +1593,7 @@ fn sprint_summary_lines(sprint: &Sprint, entries: &[DailyEntry]) -> Vec<String>
         format!("Total items: {}", entries.len()),
         String::new(),
         "Dates:".to_string(),
-    ];
+    ]);
 
     let mut by_day = BTreeMap::<String, usize>::new();
     for entry in entries {
@@ -666,13 +1609,20 @@ fn sprint_summary_lines(sprint: &Sprint, entries: &[DailyEntry]) -> Vec<String>
         lines.push(format!("- {date}: {count} items"));
     }
 
-    truncate_lines(lines, DEFAULT_TRUNCATE_LINES)
+    lines
+}
+
+fn entry_time_label(created_at: &str) -> Option<String> {
+    DateTime::parse_from_rfc3339(created_at)
+        .ok()
+        .map(|value| value.with_timezone(&Local).format("%H:%M").to_string())
 }
 
 fn build_day_text(
     date: &str,
     entries: &[DailyEntry],
     categories: &HashMap<String, String>,
+    colors: &HashMap<String, String>,
 ) -> String {
     let mut grouped = BTreeMap::<String, Vec<&DailyEntry>>::new();
 
@@ -696,9 +1646,13 @@ fn build_day_text(
     out.push_str(&format!("{date}\n\n"));
 
     for (category, items) in grouped {
+        let category = paint_category(&category, &items[0].category_id, colors);
         out.push_str(&format!("{category}\n"));
         for item in items {
-            out.push_str(&format!("- {}", item.title));
+            match entry_time_label(&item.created_at) {
+                Some(time) => out.push_str(&format!("- {time} {}", item.title)),
+                None => out.push_str(&format!("- {}", item.title)),
+            }
             if let Some(details) = &item.details {
                 out.push_str(&format!(" - {}", details));
             }
@@ -710,7 +1664,11 @@ fn build_day_text(
     out
 }
 
-fn build_all_details_text(entries: &[DailyEntry], categories: &HashMap<String, String>) -> String {
+fn build_all_details_text(
+    entries: &[DailyEntry],
+    categories: &HashMap<String, String>,
+    colors: &HashMap<String, String>,
+) -> String {
     if entries.is_empty() {
         return "No entries in this sprint yet.".to_string();
     }
@@ -736,6 +1694,7 @@ fn build_all_details_text(entries: &[DailyEntry], categories: &HashMap<String, S
     for (date, categories_for_day) in grouped {
         out.push_str(&format!("{date}\n"));
         for (category, items) in categories_for_day {
+            let category = paint_category(&category, &items[0].category_id, colors);
             out.push_str(&format!("  {category}\n"));
             for item in items {
                 out.push_str(&format!("  - {}", item.title));
@@ -751,8 +1710,15 @@ fn build_all_details_text(entries: &[DailyEntry], categories: &HashMap<String, S
     out
 }
 
-fn generate_report(conn: &Connection, sprint: &Sprint) -> Result<ReportOutput, String> {
-    let entries = list_entries_for_sprint(conn, &sprint.id)?;
+fn generate_report(
+    conn: &Connection,
+    sprint: &Sprint,
+    filters: &ReportFilters,
+) -> Result<ReportOutput, String> {
+    let entries = list_entries_for_sprint(conn, &sprint.id)?
+        .into_iter()
+        .filter(|entry| filters.matches(entry))
+        .collect::<Vec<_>>();
     let categories = list_categories_map(conn)?;
 
     let mut grouped = BTreeMap::<String, BTreeMap<String, Vec<&DailyEntry>>>::new();
@@ -773,6 +1739,9 @@ fn generate_report(conn: &Connection, sprint: &Sprint) -> Result<ReportOutput, S
 
     let mut markdown = String::new();
     markdown.push_str(&format!("# Sprint Report: {}\n\n", sprint.name));
+    if let Some(description) = &sprint.description {
+        markdown.push_str(&format!("{}\n\n", description));
+    }
     markdown.push_str(&format!("- Sprint Code: `{}`\n", sprint.code));
     markdown.push_str(&format!(
         "- Sprint Window: {} to {}\n",
@@ -785,7 +1754,9 @@ fn generate_report(conn: &Connection, sprint: &Sprint) -> Result<ReportOutput, S
     markdown.push_str(&format!("- Exported At: {}\n", now()));
     markdown.push('\n');
 
-    if grouped.is_empty() {
+    if grouped.is_empty() && !filters.is_empty() {
+        markdown.push_str("No items found for the selected filters.\n");
+    } else if grouped.is_empty() {
         markdown.push_str("No items found for this sprint.\n");
     } else {
         for (date, by_category) in grouped {
@@ -793,18 +1764,22 @@ fn generate_report(conn: &Connection, sprint: &Sprint) -> Result<ReportOutput, S
             for (category, list) in by_category {
                 markdown.push_str(&format!("### {category}\n"));
                 for item in list {
-                    markdown.push_str(&format!("- {}", item.title));
-                    if let Some(details) = &item.details {
-                        markdown.push_str(&format!(" - {}", details));
+                    markdown.push_str(&format!("- {}", escape_markdown(&item.title)));
+                    let details = item.details.as_deref();
+                    if let Some(details) = details.filter(|details| !details.contains('\n')) {
+                        markdown.push_str(&format!(" - {}", escape_markdown(details)));
                     }
                     markdown.push('\n');
+                    if let Some(details) = details.filter(|details| details.contains('\n')) {
+                        markdown.push_str(&markdown_details_block(details));
+                    }
                 }
                 markdown.push('\n');
             }
         }
     }
 
-    let mut report_path = reports_dir()?;
+    let mut report_path = reports_dir(conn)?;
     report_path.push(format!(
         "report-{}-{}.md",
         slugify(&sprint.name),
@@ -889,10 +1864,31 @@ fn sprint_label(sprint: &Sprint) -> String {
     }
 }
 
+fn within_range(date: &str, from: &Option<String>, to: &Option<String>) -> bool {
+    if let Some(start) = from {
+        if date < start.as_str() {
+            return false;
+        }
+    }
+
+    if let Some(end) = to {
+        if date > end.as_str() {
+            return false;
+        }
+    }
+
+    true
+}
+
 fn now() -> String {
     Utc::now().to_rfc3339()
 }
 
+fn next_id(prefix: &str) -> String {
+    let ts = Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    format!("{prefix}-{ts}")
+}
+
 fn slugify(raw: &str) -> String {
     let mut out = String::new();
 
@@ -0,0 +1,43 @@
+use std::collections::HashSet;
+use std::env;
+
+pub const DEFAULT_CATEGORY_NAMES: &[&str] = &["PR-Reviews", "Meeting", "Tasks"];
+pub const DEFAULT_CATEGORIES_ENV: &str = "DEVLOG_DEFAULT_CATEGORIES";
+
+pub fn default_category_names() -> Vec<String> {
+    let configured = env::var(DEFAULT_CATEGORIES_ENV)
+        .map(|raw| parse_category_names(&raw))
+        .unwrap_or_default();
+
+    if configured.is_empty() {
+        DEFAULT_CATEGORY_NAMES
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
+    } else {
+        configured
+    }
+}
+
+fn parse_category_names(raw: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+
+    raw.split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .filter(|name| seen.insert(name.to_lowercase()))
+        .map(str::to_string)
+        .collect()
+}
+
+pub fn unique_category_id(slug: &str, taken: &mut HashSet<String>) -> String {
+    let mut id = slug.to_string();
+    let mut suffix = 2;
+
+    while !taken.insert(id.clone()) {
+        id = format!("{slug}-{suffix}");
+        suffix += 1;
+    }
+
+    id
+}
@@ -1,20 +1,38 @@
-use chrono::{Duration, NaiveDate, Utc};
-use rusqlite::{params, Connection, OptionalExtension};
+mod active_sprint;
+mod defaults;
+mod markdown;
+mod title_length;
+
+use active_sprint::pick_active_sprint_id;
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, Utc};
+use markdown::{escape_markdown, markdown_details_block};
+use rusqlite::{
+    backup::Progress, params, Connection, DatabaseName, ErrorCode, OpenFlags, OptionalExtension,
+    Row,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs;
-use std::path::PathBuf;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::{Mutex, MutexGuard};
 use tauri::{
     menu::{MenuBuilder, MenuItem},
     tray::TrayIconBuilder,
-    AppHandle, Emitter, Manager, Runtime,
+    AppHandle, Emitter, Manager, Runtime, State,
 };
+use title_length::{max_title_length_db, validate_title_length, MAX_TITLE_LENGTH_SETTING};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct Category {
     id: String,
     name: String,
     created_at: String,
+    #[serde(default)]
+    color: Option<String>,
+    #[serde(default)]
+    icon: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,6 +44,12 @@ struct Sprint {
     start_date: String,
     end_date: Option<String>,
     created_at: String,
+    #[serde(default)]
+    archived: bool,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    entry_count: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -38,6 +62,30 @@ struct DailyEntry {
     title: String,
     details: Option<String>,
     created_at: String,
+    #[serde(default)]
+    updated_at: String,
+    #[serde(default)]
+    link: Option<String>,
+    #[serde(default)]
+    seq: i64,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EntryTemplate {
+    id: String,
+    category_id: String,
+    title: String,
+    details: Option<String>,
+    created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ReportTemplate {
+    name: String,
+    header: String,
+    created_at: String,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -53,12 +101,27 @@ struct AppData {
 #[derive(Debug, Deserialize)]
 struct NewCategoryInput {
     name: String,
+    color: Option<String>,
+    icon: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EnsureCategoryInput {
+    name: String,
+}
+
+#[derive(Debug, Serialize)]
+struct EnsureCategoryOutput {
+    category: Category,
+    created: bool,
 }
 
 #[derive(Debug, Deserialize)]
 struct UpdateCategoryInput {
     id: String,
     name: String,
+    color: Option<String>,
+    icon: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -67,11 +130,63 @@ struct DeleteCategoryInput {
     replacement_category_id: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+struct ReorderCategoriesInput {
+    ordered_ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReorderDayEntriesInput {
+    sprint_id: String,
+    date: String,
+    category_id: String,
+    ordered_entry_ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MergeCategoriesInput {
+    source_id: String,
+    target_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RekeyCategoryInput {
+    old_id: String,
+    new_id: String,
+}
+
 #[derive(Debug, Deserialize)]
 struct NewSprintInput {
     name: Option<String>,
     start_date: String,
     duration_days: Option<i64>,
+    description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FirstEntryInput {
+    category_id: String,
+    title: String,
+    details: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NewSprintWithEntryInput {
+    sprint: NewSprintInput,
+    entry: FirstEntryInput,
+}
+
+#[derive(Debug, Serialize)]
+struct SprintWithEntryOutput {
+    sprint: Sprint,
+    entry: DailyEntry,
+}
+
+#[derive(Debug, Deserialize)]
+struct DuplicateSprintInput {
+    source_sprint_id: String,
+    start_date: String,
+    duration_days: Option<i64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -80,9 +195,66 @@ struct UpdateSprintNameInput {
     name: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct UpdateSprintDescriptionInput {
+    id: String,
+    description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdateSprintCodeInput {
+    id: String,
+    code: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdateSprintDatesInput {
+    id: String,
+    start_date: String,
+    end_date: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SetSprintArchivedInput {
+    id: String,
+    archived: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct AutoArchiveSprintsInput {
+    older_than_days: u64,
+}
+
 #[derive(Debug, Deserialize)]
 struct DeleteSprintInput {
     id: String,
+    force: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ClearSprintEntriesInput {
+    sprint_id: String,
+    confirm_count: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListEntriesInput {
+    sprint_id: String,
+    limit: Option<usize>,
+    offset: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DateRangeEntriesInput {
+    from_date: String,
+    to_date: String,
+    categories: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CategoryEntriesInput {
+    sprint_id: String,
+    category_id: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -92,6 +264,8 @@ struct NewDailyEntryInput {
     category_id: String,
     title: String,
     details: Option<String>,
+    link: Option<String>,
+    reject_duplicates: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -101,6 +275,7 @@ struct UpdateDailyEntryInput {
     category_id: String,
     title: String,
     details: Option<String>,
+    link: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -108,1395 +283,6318 @@ struct DeleteDailyEntryInput {
     id: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct MoveEntryInput {
+    entry_id: String,
+    target_sprint_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SetEntryDateInput {
+    entry_id: String,
+    date: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct NewEntriesBatchInput {
+    entries: Vec<NewDailyEntryInput>,
+}
+
+#[derive(Debug, Serialize)]
+struct BatchResult {
+    inserted: usize,
+    ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DuplicateEntryInput {
+    entry_id: String,
+    new_date: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NewTemplateInput {
+    category_id: String,
+    title: String,
+    details: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeleteTemplateInput {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApplyTemplateInput {
+    template_id: String,
+    sprint_id: String,
+    date: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct EntryTagInput {
+    entry_id: String,
+    tag: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchEntriesInput {
+    query: String,
+    sprint_id: Option<String>,
+    limit: Option<usize>,
+}
+
 #[derive(Debug, Deserialize)]
 struct ReportInput {
     sprint_id: String,
     from_date: Option<String>,
     to_date: Option<String>,
     categories: Option<Vec<String>>,
+    format: Option<String>,
+    group_by: Option<String>,
+    write_file: Option<bool>,
+    include_toc: Option<bool>,
+    include_ids: Option<bool>,
+    include_summary: Option<bool>,
+    template_name: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
-struct MenubarSettingsInput {
-    show_icon: bool,
-    add_item_shortcut: Option<String>,
+struct CombinedReportInput {
+    sprint_ids: Vec<String>,
+    format: Option<String>,
+    from_date: Option<String>,
+    to_date: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
-struct ReportOutput {
-    markdown: String,
-    file_path: String,
-    total_items: usize,
+#[derive(Debug, Deserialize)]
+struct SaveReportTemplateInput {
+    name: String,
+    header: String,
 }
 
-const TRAY_ICON_ID: &str = "devlog-tray";
-const TRAY_MENU_ADD_ITEM_ID: &str = "tray_add_item";
-const TRAY_MENU_ADD_SPRINT_ID: &str = "tray_add_sprint";
-const TRAY_MENU_QUIT_ID: &str = "tray_quit";
-const DEFAULT_ADD_ITEM_SHORTCUT: &str = "CmdOrCtrl+Shift+N";
-
-fn now() -> String {
-    Utc::now().to_rfc3339()
+#[derive(Debug, Deserialize)]
+struct DeleteReportTemplateInput {
+    name: String,
 }
 
-fn next_id(prefix: &str) -> String {
-    let ts = Utc::now().timestamp_nanos_opt().unwrap_or(0);
-    format!("{prefix}-{ts}")
+#[derive(Debug, Clone, Copy)]
+enum ReportFormat {
+    Markdown,
+    Csv,
+    Html,
+    Json,
+    Pdf,
 }
 
-fn app_data_root(app: &AppHandle) -> Result<PathBuf, String> {
-    let root = app
-        .path()
-        .app_data_dir()
-        .map_err(|error| format!("unable to resolve app data dir: {error}"))?;
+#[derive(Debug, Clone, Copy)]
+enum SprintSortKey {
+    Created,
+    StartDate,
+    Code,
+}
 
-    fs::create_dir_all(&root).map_err(|error| format!("unable to create app data dir: {error}"))?;
-    Ok(root)
+#[derive(Debug, Clone, Copy)]
+enum ReportGrouping {
+    Day,
+    Week,
 }
 
-fn db_file_path(app: &AppHandle) -> Result<PathBuf, String> {
-    let mut path = app_data_root(app)?;
-    path.push("daily-updates.sqlite");
-    Ok(path)
+#[derive(Debug, Default, Serialize)]
+struct ImportCounts {
+    imported: usize,
+    skipped: usize,
 }
 
-fn legacy_data_file_path(app: &AppHandle) -> Result<PathBuf, String> {
-    let mut path = app_data_root(app)?;
-    path.push("daily-updates-data.json");
-    Ok(path)
+#[derive(Debug, Default, Serialize)]
+struct LegacyImportReport {
+    categories: ImportCounts,
+    sprints: ImportCounts,
+    entries: ImportCounts,
+    recoded_sprints: usize,
 }
 
-fn reports_dir(app: &AppHandle) -> Result<PathBuf, String> {
-    let mut app_data = app_data_root(app)?;
-    app_data.push("reports");
+#[derive(Debug, Deserialize)]
+struct ForceImportLegacyInput {
+    path: String,
+    on_conflict: String,
+}
 
-    fs::create_dir_all(&app_data)
-        .map_err(|error| format!("unable to create reports dir: {error}"))?;
-    Ok(app_data)
+#[derive(Debug, Deserialize)]
+struct ValidateLegacyInput {
+    path: String,
 }
 
-fn slugify(raw: &str) -> String {
-    let mut out = String::new();
+#[derive(Debug, Serialize)]
+struct ImportPreview {
+    categories: usize,
+    sprints: usize,
+    entries: usize,
+    entries_missing_sprint: usize,
+    entries_missing_category: usize,
+    invalid_dates: usize,
+}
 
-    for ch in raw.chars() {
-        if ch.is_ascii_alphanumeric() {
-            out.push(ch.to_ascii_lowercase());
-        } else if (ch.is_ascii_whitespace() || ch == '-' || ch == '_') && !out.ends_with('-') {
-            out.push('-');
-        }
-    }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LegacyConflictPolicy {
+    Skip,
+    Replace,
+}
 
-    let trimmed = out.trim_matches('-');
-    if trimmed.is_empty() {
-        "value".to_string()
-    } else {
-        trimmed.to_string()
-    }
+#[derive(Debug, Deserialize)]
+struct RestoreInput {
+    path: String,
 }
 
-fn humanize_category_id(raw: &str) -> String {
-    let clean = raw.trim().replace(['-', '_'], " ");
-    if clean.is_empty() {
-        return "Category".to_string();
-    }
+#[derive(Debug, Deserialize)]
+struct MenubarSettingsInput {
+    show_icon: bool,
+    add_item_shortcut: Option<String>,
+    add_sprint_shortcut: Option<String>,
+}
 
-    clean
-        .split_whitespace()
-        .map(|word| {
-            let mut chars = word.chars();
-            if let Some(first) = chars.next() {
-                let mut out = String::new();
-                out.push(first.to_ascii_uppercase());
-                out.push_str(chars.as_str().to_ascii_lowercase().as_str());
-                out
-            } else {
-                String::new()
-            }
-        })
-        .collect::<Vec<_>>()
-        .join(" ")
+#[derive(Debug, Serialize)]
+struct MenubarSettings {
+    show_icon: bool,
+    add_item_shortcut: String,
+    add_sprint_shortcut: Option<String>,
 }
 
-fn within_range(date: &str, from: &Option<String>, to: &Option<String>) -> bool {
-    if let Some(start) = from {
-        if date < start.as_str() {
-            return false;
+impl Default for MenubarSettings {
+    fn default() -> Self {
+        Self {
+            show_icon: true,
+            add_item_shortcut: DEFAULT_ADD_ITEM_SHORTCUT.to_string(),
+            add_sprint_shortcut: None,
         }
     }
+}
 
-    if let Some(end) = to {
-        if date > end.as_str() {
-            return false;
-        }
-    }
+#[derive(Debug, Serialize)]
+struct ReportJson {
+    sprint: Sprint,
+    exported_at: String,
+    total_items: usize,
+    days: Vec<ReportJsonDay>,
+}
 
-    true
+#[derive(Debug, Serialize)]
+struct ReportJsonDay {
+    date: String,
+    categories: Vec<ReportJsonCategory>,
 }
 
-fn sprint_number(raw: &str) -> Option<u32> {
-    let value = raw.trim().to_ascii_lowercase();
-    if value.is_empty() {
-        return None;
-    }
+#[derive(Debug, Serialize)]
+struct ReportJsonCategory {
+    name: String,
+    items: Vec<ReportJsonItem>,
+}
+
+#[derive(Debug, Serialize)]
+struct ReportJsonItem {
+    title: String,
+    details: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ReportOutput {
+    content: String,
+    markdown: String,
+    file_path: String,
+    total_items: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct EntriesPage {
+    entries: Vec<DailyEntry>,
+    total_count: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct CategoryUsage {
+    id: String,
+    name: String,
+    entry_count: usize,
+    last_used_date: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct OrphanReport {
+    missing_sprint: usize,
+    missing_category: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct RepairOrphansInput {
+    default_category_id: String,
+}
+
+#[derive(Debug, Serialize)]
+struct DbDiagnostics {
+    file_path: String,
+    file_size_bytes: u64,
+    categories: usize,
+    sprints: usize,
+    entries: usize,
+    schema_version: i64,
+    page_count: i64,
+    freelist_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct DbRecovery {
+    quarantined_path: String,
+    backup_path: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct VacuumResult {
+    bytes_before: u64,
+    bytes_after: u64,
+    reclaimed: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExportJsonlInput {
+    sprint_id: Option<String>,
+    out_path: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ExportJsonlOutput {
+    file_path: String,
+    rows_written: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct ReportFile {
+    file_name: String,
+    path: String,
+    size_bytes: u64,
+    created: String,
+    format: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct PruneReportsInput {
+    older_than_days: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+struct PruneResult {
+    deleted: usize,
+    freed_bytes: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct VelocityTrendInput {
+    last_n: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+struct SprintVelocity {
+    sprint_code: String,
+    sprint_name: String,
+    total_entries: usize,
+    entries_per_day: f64,
+}
+
+#[derive(Debug, Serialize)]
+struct ActivityStats {
+    current_streak: usize,
+    longest_streak: usize,
+    total_days_logged: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct ImportMarkdownInput {
+    sprint_id: String,
+    markdown: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ImportMarkdownOutput {
+    imported: usize,
+    skipped: usize,
+    created_categories: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct ImportCsvInput {
+    sprint_id: String,
+    csv: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ImportCsvOutput {
+    imported: usize,
+    skipped: usize,
+    created_categories: usize,
+    errors: Vec<String>,
+}
+
+struct MarkdownImportItem {
+    date: String,
+    category_label: String,
+    title: String,
+    details: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct MoveEntryOutput {
+    entry: DailyEntry,
+    date_outside_window: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct SetEntryDateOutput {
+    entry: DailyEntry,
+    within_sprint_window: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct RecentEntriesInput {
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+struct RecentEntry {
+    entry: DailyEntry,
+    sprint_code: String,
+    sprint_name: String,
+    category_name: String,
+}
+
+#[derive(Debug, Serialize)]
+struct SprintStats {
+    total_entries: usize,
+    entries_by_category: Vec<(String, usize)>,
+    entries_by_date: Vec<(String, usize)>,
+    active_days: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct BurndownPoint {
+    date: String,
+    entries_that_day: usize,
+    cumulative: usize,
+}
+
+const TRAY_ICON_ID: &str = "devlog-tray";
+const TRAY_MENU_ADD_ITEM_ID: &str = "tray_add_item";
+const TRAY_MENU_ADD_SPRINT_ID: &str = "tray_add_sprint";
+const TRAY_MENU_QUIT_ID: &str = "tray_quit";
+const DEFAULT_ADD_ITEM_SHORTCUT: &str = "CmdOrCtrl+Shift+N";
+const DEFAULT_SPRINT_DURATION_DAYS: i64 = 14;
+const MIN_SPRINT_DURATION_DAYS: i64 = 1;
+const MAX_SPRINT_DURATION_DAYS: i64 = 90;
+const REPORT_DIRECTORY_SETTING: &str = "report_directory";
+const DEFAULT_SPRINT_DURATION_SETTING: &str = "default_sprint_duration";
+const UNORDERED_CATEGORY_POSITION: i64 = 1_000_000;
+const DEFAULT_VELOCITY_SPRINTS: usize = 5;
+const DEFAULT_PRUNE_REPORTS_DAYS: u64 = 30;
+const DEFAULT_REPORT_TEMPLATE_NAME: &str = "default";
+const MAX_BURNDOWN_DAYS: i64 = 5 * 366;
+const DEFAULT_REPORT_HEADER: &str = "# Sprint Report: {sprint_name}\n\n{description}- Sprint Code: `{sprint_code}`\n- Sprint Window: {start_date} to {end_date}\n- Exported At: {exported_at}\n\n";
+const MENUBAR_SHOW_ICON_SETTING: &str = "menubar_show_icon";
+const ADD_ITEM_SHORTCUT_SETTING: &str = "add_item_shortcut";
+const ADD_SPRINT_SHORTCUT_SETTING: &str = "add_sprint_shortcut";
+
+fn now() -> String {
+    Utc::now().to_rfc3339()
+}
+
+fn next_id(prefix: &str) -> String {
+    let ts = Utc::now().timestamp_nanos_opt().unwrap_or(0);
+    format!("{prefix}-{ts}")
+}
+
+struct DbState(Mutex<Connection>);
+
+struct DbRecoveryState(Mutex<Option<DbRecovery>>);
+
+impl DbState {
+    fn conn(&self) -> Result<MutexGuard<'_, Connection>, String> {
+        self.0
+            .lock()
+            .map_err(|_| "database connection is unavailable".to_string())
+    }
+}
+
+fn app_data_root(app: &AppHandle) -> Result<PathBuf, String> {
+    let root = app
+        .path()
+        .app_data_dir()
+        .map_err(|error| format!("unable to resolve app data dir: {error}"))?;
+
+    fs::create_dir_all(&root).map_err(|error| format!("unable to create app data dir: {error}"))?;
+    Ok(root)
+}
+
+fn db_file_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let mut path = app_data_root(app)?;
+    path.push("daily-updates.sqlite");
+    Ok(path)
+}
+
+fn legacy_data_file_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let mut path = app_data_root(app)?;
+    path.push("daily-updates-data.json");
+    Ok(path)
+}
+
+fn default_reports_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let mut app_data = app_data_root(app)?;
+    app_data.push("reports");
+
+    fs::create_dir_all(&app_data)
+        .map_err(|error| format!("unable to create reports dir: {error}"))?;
+    Ok(app_data)
+}
+
+fn reports_dir(app: &AppHandle, conn: &Connection) -> Result<PathBuf, String> {
+    let Some(custom) = get_setting_db(conn, REPORT_DIRECTORY_SETTING)? else {
+        return default_reports_dir(app);
+    };
+
+    let path = PathBuf::from(custom);
+    fs::create_dir_all(&path)
+        .map_err(|error| format!("unable to create reports dir {}: {error}", path.display()))?;
+    Ok(path)
+}
+
+fn ensure_directory_writable(path: &Path) -> Result<(), String> {
+    fs::create_dir_all(path)
+        .map_err(|error| format!("unable to create directory {}: {error}", path.display()))?;
+
+    let probe = path.join(".devlog-write-test");
+    fs::write(&probe, b"ok")
+        .map_err(|error| format!("directory {} is not writable: {error}", path.display()))?;
+    let _ = fs::remove_file(&probe);
+    Ok(())
+}
+
+fn backups_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let mut path = default_reports_dir(app)?;
+    path.push("backups");
+
+    fs::create_dir_all(&path).map_err(|error| format!("unable to create backups dir: {error}"))?;
+    Ok(path)
+}
+
+fn slugify(raw: &str) -> String {
+    let mut out = String::new();
+
+    for ch in raw.chars() {
+        if ch.is_ascii_alphanumeric() {
+            out.push(ch.to_ascii_lowercase());
+        } else if (ch.is_ascii_whitespace() || ch == '-' || ch == '_') && !out.ends_with('-') {
+            out.push('-');
+        }
+    }
+
+    let trimmed = out.trim_matches('-');
+    if trimmed.is_empty() {
+        "value".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+fn humanize_category_id(raw: &str) -> String {
+    let clean = raw.trim().replace(['-', '_'], " ");
+    if clean.is_empty() {
+        return "Category".to_string();
+    }
+
+    clean
+        .split_whitespace()
+        .map(|word| {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                let mut out = String::new();
+                out.push(first.to_ascii_uppercase());
+                out.push_str(chars.as_str().to_ascii_lowercase().as_str());
+                out
+            } else {
+                String::new()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn normalize_details(value: Option<String>) -> Option<String> {
+    value.and_then(|raw| {
+        let trimmed = raw.trim().to_string();
+        if trimmed.is_empty() {
+            None
+        } else {
+            Some(trimmed)
+        }
+    })
+}
+
+fn normalize_link(value: Option<String>) -> Result<Option<String>, String> {
+    let Some(link) = normalize_details(value) else {
+        return Ok(None);
+    };
+
+    if !link.starts_with("http://") && !link.starts_with("https://") {
+        return Err("link must be an absolute URL".to_string());
+    }
+
+    Ok(Some(link))
+}
+
+fn escape_like(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len());
+    for ch in raw.chars() {
+        if ch == '\\' || ch == '%' || ch == '_' {
+            out.push('\\');
+        }
+        out.push(ch);
+    }
+    out
+}
+
+fn normalize_color(value: Option<String>) -> Result<Option<String>, String> {
+    let Some(raw) = value else {
+        return Ok(None);
+    };
+
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+
+    let valid = trimmed.len() == 7
+        && trimmed.starts_with('#')
+        && trimmed[1..].chars().all(|ch| ch.is_ascii_hexdigit());
+
+    if !valid {
+        return Err("invalid color".to_string());
+    }
+
+    Ok(Some(trimmed.to_ascii_lowercase()))
+}
+
+fn normalize_icon(value: Option<String>) -> Option<String> {
+    value.and_then(|raw| {
+        let trimmed = raw.trim();
+        if trimmed.is_empty() {
+            None
+        } else {
+            Some(trimmed.to_string())
+        }
+    })
+}
+
+fn validate_sprint_duration(duration_days: i64) -> Result<i64, String> {
+    if !(MIN_SPRINT_DURATION_DAYS..=MAX_SPRINT_DURATION_DAYS).contains(&duration_days) {
+        return Err(format!(
+            "duration_days must be between {MIN_SPRINT_DURATION_DAYS} and {MAX_SPRINT_DURATION_DAYS}"
+        ));
+    }
+
+    Ok(duration_days)
+}
+
+fn normalize_tag(raw: &str) -> Option<String> {
+    let tag = raw.trim().to_lowercase();
+    if tag.is_empty() {
+        None
+    } else {
+        Some(tag)
+    }
+}
+
+fn within_range(date: &str, from: &Option<String>, to: &Option<String>) -> bool {
+    if let Some(start) = from {
+        if date < start.as_str() {
+            return false;
+        }
+    }
+
+    if let Some(end) = to {
+        if date > end.as_str() {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn sprint_number(raw: &str) -> Option<u32> {
+    let value = raw.trim().to_ascii_lowercase();
+    if value.is_empty() {
+        return None;
+    }
 
     if let Ok(number) = value.parse::<u32>() {
         return Some(number);
     }
 
-    let rest = value
-        .strip_prefix("sprint-")
-        .or_else(|| value.strip_prefix("sprint "))
-        .or_else(|| value.strip_prefix("sprint"))?;
+    let rest = value
+        .strip_prefix("sprint-")
+        .or_else(|| value.strip_prefix("sprint "))
+        .or_else(|| value.strip_prefix("sprint"))?;
+
+    let digits = rest
+        .trim()
+        .trim_start_matches('-')
+        .trim_start_matches('_')
+        .trim();
+
+    digits.parse::<u32>().ok()
+}
+
+fn format_sprint_code(number: u32) -> String {
+    format!("sprint-{number}")
+}
+
+fn default_categories() -> Vec<Category> {
+    let created_at = now();
+    let mut taken_ids = HashSet::new();
+
+    defaults::default_category_names()
+        .into_iter()
+        .map(|name| Category {
+            id: defaults::unique_category_id(&slugify(&name), &mut taken_ids),
+            name,
+            created_at: created_at.clone(),
+            color: None,
+            icon: None,
+        })
+        .collect()
+}
+
+fn ensure_default_categories(data: &mut AppData) {
+    if data.categories.is_empty() {
+        data.categories = default_categories();
+    }
+}
+
+fn assign_missing_sprint_codes(data: &mut AppData) -> bool {
+    let mut changed = false;
+    let mut highest = data
+        .sprints
+        .iter()
+        .filter_map(|sprint| sprint_number(&sprint.code).or_else(|| sprint_number(&sprint.name)))
+        .max()
+        .unwrap_or(0);
+
+    let mut index_list = (0..data.sprints.len()).collect::<Vec<_>>();
+    index_list.sort_by(|left, right| {
+        data.sprints[*left]
+            .created_at
+            .cmp(&data.sprints[*right].created_at)
+    });
+
+    for index in &index_list {
+        let sprint = &mut data.sprints[*index];
+
+        if sprint.code.trim().is_empty() {
+            if let Some(number) = sprint_number(&sprint.name) {
+                sprint.code = format_sprint_code(number);
+                if number > highest {
+                    highest = number;
+                }
+                changed = true;
+            }
+        } else if let Some(number) = sprint_number(&sprint.code) {
+            let normalized = format_sprint_code(number);
+            if sprint.code != normalized {
+                sprint.code = normalized;
+                changed = true;
+            }
+            if number > highest {
+                highest = number;
+            }
+        }
+    }
+
+    for index in &index_list {
+        let sprint = &mut data.sprints[*index];
+        if sprint.code.trim().is_empty() {
+            highest += 1;
+            sprint.code = format_sprint_code(highest);
+            changed = true;
+        }
+    }
+
+    changed
+}
+
+fn column_exists(conn: &Connection, table: &str, column: &str) -> Result<bool, String> {
+    let mut stmt = conn
+        .prepare(&format!("PRAGMA table_info({table})"))
+        .map_err(|error| format!("failed to inspect {table} columns: {error}"))?;
+
+    let names = stmt
+        .query_map([], |row| row.get::<_, String>(1))
+        .map_err(|error| format!("failed to read {table} columns: {error}"))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect {table} columns: {error}"))?;
+
+    Ok(names.iter().any(|name| name.eq_ignore_ascii_case(column)))
+}
+
+fn ensure_column(
+    conn: &Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<(), String> {
+    if column_exists(conn, table, column)? {
+        return Ok(());
+    }
+
+    conn.execute_batch(&format!(
+        "ALTER TABLE {table} ADD COLUMN {column} {definition};"
+    ))
+    .map_err(|error| format!("failed to add {table}.{column} column: {error}"))
+}
+
+fn init_schema(conn: &Connection) -> Result<(), String> {
+    conn.execute_batch(
+        "
+        PRAGMA foreign_keys = ON;
+
+        CREATE TABLE IF NOT EXISTS categories (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL UNIQUE COLLATE NOCASE,
+            created_at TEXT NOT NULL,
+            color TEXT,
+            icon TEXT,
+            position INTEGER NOT NULL DEFAULT 1000000
+        );
+
+        CREATE TABLE IF NOT EXISTS sprints (
+            id TEXT PRIMARY KEY,
+            code TEXT NOT NULL UNIQUE,
+            name TEXT NOT NULL,
+            start_date TEXT NOT NULL,
+            end_date TEXT,
+            created_at TEXT NOT NULL,
+            archived INTEGER NOT NULL DEFAULT 0,
+            code_locked INTEGER NOT NULL DEFAULT 0,
+            description TEXT
+        );
+
+        CREATE TABLE IF NOT EXISTS entries (
+            id TEXT PRIMARY KEY,
+            sprint_id TEXT NOT NULL,
+            date TEXT NOT NULL,
+            category_id TEXT NOT NULL,
+            title TEXT NOT NULL,
+            details TEXT,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL DEFAULT '',
+            link TEXT,
+            seq INTEGER NOT NULL DEFAULT 0,
+            FOREIGN KEY (sprint_id) REFERENCES sprints(id) ON DELETE CASCADE,
+            FOREIGN KEY (category_id) REFERENCES categories(id) ON DELETE RESTRICT
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_entries_sprint_date
+            ON entries (sprint_id, date, category_id, created_at);
+
+        CREATE TABLE IF NOT EXISTS settings (
+            key TEXT PRIMARY KEY,
+            value TEXT NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS templates (
+            id TEXT PRIMARY KEY,
+            category_id TEXT NOT NULL,
+            title TEXT NOT NULL,
+            details TEXT,
+            created_at TEXT NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS entry_tags (
+            entry_id TEXT NOT NULL,
+            tag TEXT NOT NULL,
+            PRIMARY KEY (entry_id, tag),
+            FOREIGN KEY (entry_id) REFERENCES entries(id) ON DELETE CASCADE
+        );
+
+        CREATE TABLE IF NOT EXISTS report_templates (
+            name TEXT PRIMARY KEY COLLATE NOCASE,
+            header TEXT NOT NULL,
+            created_at TEXT NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS schema_migrations (
+            version INTEGER PRIMARY KEY,
+            applied_at TEXT NOT NULL
+        );
+        ",
+    )
+    .map_err(|error| format!("failed to initialize database schema: {error}"))?;
+
+    run_migrations(conn)?;
+
+    conn.execute(
+        "UPDATE entries SET updated_at = created_at WHERE updated_at = ''",
+        [],
+    )
+    .map_err(|error| format!("failed to backfill entry updated_at: {error}"))?;
+
+    Ok(())
+}
+
+type Migration = fn(&Connection) -> Result<(), String>;
+
+const MIGRATIONS: &[Migration] = &[
+    migrate_category_display,
+    migrate_category_position,
+    migrate_sprint_flags,
+    migrate_entry_updated_at,
+    migrate_entry_link,
+    migrate_entry_seq,
+    migrate_sprint_description,
+    migrate_entry_order_index,
+];
+
+fn migrate_category_display(conn: &Connection) -> Result<(), String> {
+    ensure_column(conn, "categories", "color", "TEXT")?;
+    ensure_column(conn, "categories", "icon", "TEXT")
+}
+
+fn migrate_category_position(conn: &Connection) -> Result<(), String> {
+    ensure_column(
+        conn,
+        "categories",
+        "position",
+        &format!("INTEGER NOT NULL DEFAULT {UNORDERED_CATEGORY_POSITION}"),
+    )
+}
+
+fn migrate_sprint_flags(conn: &Connection) -> Result<(), String> {
+    ensure_column(conn, "sprints", "archived", "INTEGER NOT NULL DEFAULT 0")?;
+    ensure_column(conn, "sprints", "code_locked", "INTEGER NOT NULL DEFAULT 0")
+}
+
+fn migrate_entry_updated_at(conn: &Connection) -> Result<(), String> {
+    ensure_column(conn, "entries", "updated_at", "TEXT NOT NULL DEFAULT ''")
+}
+
+fn migrate_entry_link(conn: &Connection) -> Result<(), String> {
+    ensure_column(conn, "entries", "link", "TEXT")
+}
+
+fn migrate_entry_seq(conn: &Connection) -> Result<(), String> {
+    ensure_column(conn, "entries", "seq", "INTEGER NOT NULL DEFAULT 0")
+}
+
+fn migrate_sprint_description(conn: &Connection) -> Result<(), String> {
+    ensure_column(conn, "sprints", "description", "TEXT")
+}
+
+fn migrate_entry_order_index(conn: &Connection) -> Result<(), String> {
+    ensure_column(conn, "entries", "order_index", "INTEGER NOT NULL DEFAULT 0")
+}
+
+fn schema_version_db(conn: &Connection) -> Result<i64, String> {
+    conn.query_row("PRAGMA user_version", [], |row| row.get(0))
+        .map_err(|error| format!("failed to read schema version: {error}"))
+}
+
+fn run_migrations(conn: &Connection) -> Result<(), String> {
+    let current = schema_version_db(conn)?;
+
+    for (index, migrate) in MIGRATIONS.iter().enumerate() {
+        let version = index as i64 + 1;
+        if version <= current {
+            continue;
+        }
+
+        let tx = conn
+            .unchecked_transaction()
+            .map_err(|error| format!("failed to start migration {version}: {error}"))?;
+
+        migrate(&tx)?;
+
+        tx.execute(
+            "INSERT OR REPLACE INTO schema_migrations (version, applied_at) VALUES (?1, ?2)",
+            params![version, now()],
+        )
+        .map_err(|error| format!("failed to record migration {version}: {error}"))?;
+        tx.execute_batch(&format!("PRAGMA user_version = {version};"))
+            .map_err(|error| format!("failed to set schema version {version}: {error}"))?;
+
+        tx.commit()
+            .map_err(|error| format!("failed to commit migration {version}: {error}"))?;
+    }
+
+    Ok(())
+}
+
+fn db_is_empty(conn: &Connection) -> Result<bool, String> {
+    let categories_count: i64 = conn
+        .query_row("SELECT COUNT(*) FROM categories", [], |row| row.get(0))
+        .map_err(|error| format!("failed to count categories: {error}"))?;
+    let sprints_count: i64 = conn
+        .query_row("SELECT COUNT(*) FROM sprints", [], |row| row.get(0))
+        .map_err(|error| format!("failed to count sprints: {error}"))?;
+    let entries_count: i64 = conn
+        .query_row("SELECT COUNT(*) FROM entries", [], |row| row.get(0))
+        .map_err(|error| format!("failed to count entries: {error}"))?;
+
+    Ok(categories_count == 0 && sprints_count == 0 && entries_count == 0)
+}
+
+fn migrate_legacy_json_if_needed(app: &AppHandle, conn: &mut Connection) -> Result<(), String> {
+    if !db_is_empty(conn)? {
+        return Ok(());
+    }
+
+    let legacy_path = legacy_data_file_path(app)?;
+    if !legacy_path.exists() {
+        return Ok(());
+    }
+
+    let legacy = read_legacy_data(&legacy_path)?;
+    import_legacy_data_db(conn, legacy, LegacyConflictPolicy::Skip)?;
+    Ok(())
+}
+
+fn read_legacy_data(path: &Path) -> Result<AppData, String> {
+    let raw = fs::read_to_string(path).map_err(|error| {
+        format!(
+            "unable to read legacy data file {}: {error}",
+            path.display()
+        )
+    })?;
+
+    serde_json::from_str(&raw)
+        .map_err(|error| format!("invalid legacy data format in {}: {error}", path.display()))
+}
+
+fn sprint_code_taken_db(conn: &Connection, code: &str, excluding_id: &str) -> Result<bool, String> {
+    let existing = conn
+        .query_row(
+            "SELECT id FROM sprints WHERE code = ?1 AND id <> ?2 LIMIT 1",
+            params![code, excluding_id],
+            |row| row.get::<_, String>(0),
+        )
+        .optional()
+        .map_err(|error| format!("failed to check sprint code uniqueness: {error}"))?;
+
+    Ok(existing.is_some())
+}
+
+fn import_legacy_data_db(
+    conn: &mut Connection,
+    mut legacy: AppData,
+    policy: LegacyConflictPolicy,
+) -> Result<LegacyImportReport, String> {
+    let replace = policy == LegacyConflictPolicy::Replace;
+    let mut report = LegacyImportReport::default();
+
+    ensure_default_categories(&mut legacy);
+    assign_missing_sprint_codes(&mut legacy);
+
+    let mut known_category_ids = legacy
+        .categories
+        .iter()
+        .map(|category| category.id.clone())
+        .collect::<HashSet<_>>();
+
+    for entry in &legacy.entries {
+        let category_id = entry.category_id.trim();
+        if category_id.is_empty() || known_category_ids.contains(category_id) {
+            continue;
+        }
+
+        legacy.categories.push(Category {
+            id: category_id.to_string(),
+            name: humanize_category_id(category_id),
+            created_at: now(),
+            color: None,
+            icon: None,
+        });
+        known_category_ids.insert(category_id.to_string());
+    }
+
+    let tx = conn
+        .transaction()
+        .map_err(|error| format!("failed to start migration transaction: {error}"))?;
+
+    let mut category_remap = HashMap::new();
+    for category in &legacy.categories {
+        if category.id.trim().is_empty() || category.name.trim().is_empty() {
+            report.categories.skipped += 1;
+            continue;
+        }
+
+        let mut changed = tx.execute(
+            "INSERT OR IGNORE INTO categories (id, name, created_at, color, icon) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                category.id,
+                category.name,
+                category.created_at,
+                category.color,
+                category.icon
+            ],
+        )
+        .map_err(|error| format!("failed to migrate category {}: {error}", category.id))?;
+
+        if replace && changed == 0 {
+            changed = tx
+                .execute(
+                    "UPDATE OR IGNORE categories SET name = ?1, color = ?2, icon = ?3 WHERE id = ?4",
+                    params![category.name, category.color, category.icon, category.id],
+                )
+                .map_err(|error| format!("failed to replace category {}: {error}", category.id))?;
+        }
+
+        if changed > 0 {
+            report.categories.imported += 1;
+        } else {
+            report.categories.skipped += 1;
+        }
+
+        if category_exists(&tx, category.id.as_str())? {
+            category_remap.insert(category.id.clone(), category.id.clone());
+            continue;
+        }
+
+        let existing_id = tx
+            .query_row(
+                "SELECT id FROM categories WHERE lower(name) = lower(?1) LIMIT 1",
+                params![category.name],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|error| format!("failed to match category {}: {error}", category.id))?;
+
+        if let Some(existing_id) = existing_id {
+            category_remap.insert(category.id.clone(), existing_id);
+        }
+    }
+
+    let mut migrated_sprint_ids = HashSet::new();
+    for sprint in &legacy.sprints {
+        if sprint.id.trim().is_empty() || sprint.start_date.trim().is_empty() {
+            report.sprints.skipped += 1;
+            continue;
+        }
+
+        let mut code = if sprint.code.trim().is_empty() {
+            format_sprint_code(Utc::now().timestamp_subsec_nanos())
+        } else {
+            sprint.code.trim().to_string()
+        };
+
+        if sprint_code_taken_db(&tx, &code, sprint.id.as_str())? {
+            code = next_sprint_code_db(&tx)?;
+            report.recoded_sprints += 1;
+        }
+
+        let name = if sprint.name.trim().is_empty() {
+            code.clone()
+        } else {
+            sprint.name.trim().to_string()
+        };
+
+        let mut changed = tx.execute(
+            "INSERT OR IGNORE INTO sprints (id, code, name, start_date, end_date, created_at, archived, description) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                sprint.id,
+                code,
+                name,
+                sprint.start_date,
+                sprint.end_date,
+                sprint.created_at,
+                sprint.archived,
+                sprint.description
+            ],
+        )
+        .map_err(|error| format!("failed to migrate sprint {}: {error}", sprint.id))?;
+
+        if replace && changed == 0 {
+            changed = tx
+                .execute(
+                    "UPDATE OR IGNORE sprints
+                     SET code = ?1, name = ?2, start_date = ?3, end_date = ?4, archived = ?5, description = ?6
+                     WHERE id = ?7",
+                    params![
+                        code,
+                        name,
+                        sprint.start_date,
+                        sprint.end_date,
+                        sprint.archived,
+                        sprint.description,
+                        sprint.id
+                    ],
+                )
+                .map_err(|error| format!("failed to replace sprint {}: {error}", sprint.id))?;
+        }
+
+        if changed > 0 {
+            report.sprints.imported += 1;
+        } else {
+            report.sprints.skipped += 1;
+        }
+
+        if sprint_exists(&tx, sprint.id.as_str())? {
+            migrated_sprint_ids.insert(sprint.id.clone());
+        }
+    }
+
+    let insert_entry_sql = if replace {
+        "INSERT OR REPLACE INTO entries (id, sprint_id, date, category_id, title, details, created_at, updated_at, link, order_index)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9,
+                 (SELECT COALESCE(MAX(order_index) + 1, 0) FROM entries WHERE sprint_id = ?2 AND date = ?3 AND category_id = ?4))"
+    } else {
+        "INSERT OR IGNORE INTO entries (id, sprint_id, date, category_id, title, details, created_at, updated_at, link, order_index)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9,
+                 (SELECT COALESCE(MAX(order_index) + 1, 0) FROM entries WHERE sprint_id = ?2 AND date = ?3 AND category_id = ?4))"
+    };
+
+    for entry in &legacy.entries {
+        if entry.sprint_id.trim().is_empty()
+            || entry.category_id.trim().is_empty()
+            || entry.title.trim().is_empty()
+            || entry.date.trim().is_empty()
+        {
+            report.entries.skipped += 1;
+            continue;
+        }
+
+        if !migrated_sprint_ids.contains(&entry.sprint_id) {
+            report.entries.skipped += 1;
+            continue;
+        }
+
+        let Some(category_id) = category_remap.get(entry.category_id.trim()) else {
+            report.entries.skipped += 1;
+            continue;
+        };
+
+        let entry_id = if entry.id.trim().is_empty() {
+            next_id("entry-import")
+        } else {
+            entry.id.clone()
+        };
+
+        let changed = tx
+            .execute(
+                insert_entry_sql,
+                params![
+                    entry_id,
+                    entry.sprint_id,
+                    entry.date,
+                    category_id,
+                    entry.title,
+                    entry.details,
+                    entry.created_at,
+                    if entry.updated_at.trim().is_empty() {
+                        &entry.created_at
+                    } else {
+                        &entry.updated_at
+                    },
+                    entry.link
+                ],
+            )
+            .map_err(|error| format!("failed to migrate entry {}: {error}", entry.id))?;
+
+        if changed > 0 {
+            report.entries.imported += 1;
+        } else {
+            report.entries.skipped += 1;
+        }
+
+        for tag in entry.tags.iter().filter_map(|tag| normalize_tag(tag)) {
+            tx.execute(
+                "INSERT OR IGNORE INTO entry_tags (entry_id, tag) VALUES (?1, ?2)",
+                params![entry_id, tag],
+            )
+            .map_err(|error| format!("failed to migrate tags for entry {}: {error}", entry.id))?;
+        }
+    }
+
+    ensure_entry_seq_db(&tx)?;
+
+    tx.commit()
+        .map_err(|error| format!("failed to commit legacy migration: {error}"))?;
+
+    Ok(report)
+}
+
+fn ensure_entry_seq_db(conn: &Connection) -> Result<(), String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, sprint_id, seq FROM entries ORDER BY sprint_id, seq = 0, seq, created_at, id",
+        )
+        .map_err(|error| format!("failed to load entries for numbering: {error}"))?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, i64>(2)?,
+            ))
+        })
+        .map_err(|error| format!("failed to read entry rows: {error}"))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect entry rows: {error}"))?;
+
+    let mut highest = HashMap::<String, i64>::new();
+    let mut updates = Vec::<(String, i64)>::new();
+
+    for (id, sprint_id, seq) in rows {
+        let current = highest.entry(sprint_id).or_insert(0);
+        if seq > 0 {
+            *current = (*current).max(seq);
+        } else {
+            *current += 1;
+            updates.push((id, *current));
+        }
+    }
+
+    for (id, seq) in updates {
+        conn.execute(
+            "UPDATE entries SET seq = ?1 WHERE id = ?2",
+            params![seq, id],
+        )
+        .map_err(|error| format!("failed to number entry {id}: {error}"))?;
+    }
+
+    Ok(())
+}
+
+fn next_entry_seq_db(conn: &Connection, sprint_id: &str) -> Result<i64, String> {
+    conn.query_row(
+        "SELECT COALESCE(MAX(seq), 0) + 1 FROM entries WHERE sprint_id = ?1",
+        params![sprint_id],
+        |row| row.get(0),
+    )
+    .map_err(|error| format!("failed to compute entry number: {error}"))
+}
+
+fn next_order_index_db(
+    conn: &Connection,
+    sprint_id: &str,
+    date: &str,
+    category_id: &str,
+) -> Result<i64, String> {
+    conn.query_row(
+        "SELECT COALESCE(MAX(order_index) + 1, 0) FROM entries WHERE sprint_id = ?1 AND date = ?2 AND category_id = ?3",
+        params![sprint_id, date, category_id],
+        |row| row.get(0),
+    )
+    .map_err(|error| format!("failed to compute entry position: {error}"))
+}
+
+fn reassign_category_entries_db(
+    conn: &Connection,
+    from_id: &str,
+    to_id: &str,
+) -> Result<usize, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, sprint_id, date FROM entries
+             WHERE category_id = ?1
+             ORDER BY sprint_id, date, order_index, created_at",
+        )
+        .map_err(|error| format!("failed to prepare category entries query: {error}"))?;
+
+    let entries = stmt
+        .query_map(params![from_id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })
+        .map_err(|error| format!("failed to query category entries: {error}"))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect category entries: {error}"))?;
+
+    let moved = entries.len();
+    for (id, sprint_id, date) in entries {
+        conn.execute(
+            "UPDATE entries SET category_id = ?1, order_index = ?2 WHERE id = ?3",
+            params![
+                to_id,
+                next_order_index_db(conn, &sprint_id, &date, to_id)?,
+                id
+            ],
+        )
+        .map_err(|error| format!("failed to reassign category entries: {error}"))?;
+    }
+
+    Ok(moved)
+}
+
+fn ensure_default_categories_db(conn: &Connection) -> Result<(), String> {
+    let count: i64 = conn
+        .query_row("SELECT COUNT(*) FROM categories", [], |row| row.get(0))
+        .map_err(|error| format!("failed to count categories: {error}"))?;
+
+    if count > 0 {
+        return Ok(());
+    }
+
+    for category in default_categories() {
+        conn.execute(
+            "INSERT INTO categories (id, name, created_at) VALUES (?1, ?2, ?3)",
+            params![category.id, category.name, category.created_at],
+        )
+        .map_err(|error| format!("failed to seed default category {}: {error}", category.id))?;
+    }
+
+    Ok(())
+}
+
+fn ensure_default_report_template_db(conn: &Connection) -> Result<(), String> {
+    conn.execute(
+        "INSERT OR IGNORE INTO report_templates (name, header, created_at) VALUES (?1, ?2, ?3)",
+        params![DEFAULT_REPORT_TEMPLATE_NAME, DEFAULT_REPORT_HEADER, now()],
+    )
+    .map_err(|error| format!("failed to seed default report template: {error}"))?;
+
+    Ok(())
+}
+
+fn migrate_preview_category_db(conn: &Connection) -> Result<(), String> {
+    let preview_created_at = conn
+        .query_row(
+            "SELECT created_at FROM categories WHERE id = 'preview' LIMIT 1",
+            [],
+            |row| row.get::<_, String>(0),
+        )
+        .optional()
+        .map_err(|error| format!("failed to query legacy preview category: {error}"))?;
+
+    let Some(preview_created_at) = preview_created_at else {
+        return Ok(());
+    };
+
+    let target_id = conn
+        .query_row(
+            "SELECT id FROM categories WHERE id = 'pr-reviews' LIMIT 1",
+            [],
+            |row| row.get::<_, String>(0),
+        )
+        .optional()
+        .map_err(|error| format!("failed to check PR-Reviews category id: {error}"))?
+        .or(conn
+            .query_row(
+                "SELECT id FROM categories WHERE lower(name) = lower('PR-Reviews') LIMIT 1",
+                [],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|error| format!("failed to check PR-Reviews category name: {error}"))?);
+
+    let target_id = if let Some(existing_id) = target_id {
+        existing_id
+    } else {
+        conn.execute(
+            "INSERT INTO categories (id, name, created_at) VALUES (?1, ?2, ?3)",
+            params!["pr-reviews", "PR-Reviews", preview_created_at],
+        )
+        .map_err(|error| format!("failed to create PR-Reviews category: {error}"))?;
+        "pr-reviews".to_string()
+    };
+
+    conn.execute(
+        "UPDATE entries SET category_id = ?1 WHERE category_id = 'preview'",
+        params![target_id],
+    )
+    .map_err(|error| format!("failed to move preview entries to PR-Reviews: {error}"))?;
+
+    conn.execute("DELETE FROM categories WHERE id = 'preview'", [])
+        .map_err(|error| format!("failed to remove legacy preview category: {error}"))?;
+
+    conn.execute(
+        "UPDATE categories SET name = 'PR-Reviews' WHERE id = 'pr-reviews'",
+        [],
+    )
+    .map_err(|error| format!("failed to normalize PR-Reviews category name: {error}"))?;
+
+    Ok(())
+}
+
+fn ensure_sprint_codes_db(conn: &Connection) -> Result<(), String> {
+    let mut stmt = conn
+        .prepare("SELECT id, code, name, created_at, code_locked FROM sprints ORDER BY created_at")
+        .map_err(|error| format!("failed to load sprints for code normalization: {error}"))?;
+
+    let mapped = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, bool>(4)?,
+            ))
+        })
+        .map_err(|error| format!("failed to read sprint rows: {error}"))?;
+
+    let mut rows = mapped
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect sprint rows: {error}"))?;
+
+    rows.sort_by(|left, right| left.3.cmp(&right.3));
+
+    let mut used_numbers = HashSet::<u32>::new();
+    let mut highest = 0u32;
+    let mut updates = Vec::<(String, String)>::new();
+
+    for (_, code, _, _, locked) in &rows {
+        if let Some(value) = sprint_number(code).filter(|_| *locked) {
+            used_numbers.insert(value);
+            highest = highest.max(value);
+        }
+    }
+
+    for (id, code, name, _, locked) in &rows {
+        if *locked {
+            continue;
+        }
+
+        let parsed = sprint_number(code).or_else(|| sprint_number(name));
+
+        let chosen = if let Some(value) = parsed {
+            if used_numbers.contains(&value) {
+                let mut candidate = highest + 1;
+                while used_numbers.contains(&candidate) {
+                    candidate += 1;
+                }
+                candidate
+            } else {
+                value
+            }
+        } else {
+            let mut candidate = highest + 1;
+            while used_numbers.contains(&candidate) {
+                candidate += 1;
+            }
+            candidate
+        };
+
+        used_numbers.insert(chosen);
+        if chosen > highest {
+            highest = chosen;
+        }
+
+        let normalized = format_sprint_code(chosen);
+        if code != &normalized {
+            updates.push((id.clone(), normalized));
+        }
+    }
+
+    for (id, code) in updates {
+        conn.execute(
+            "UPDATE sprints SET code = ?1 WHERE id = ?2",
+            params![code, id],
+        )
+        .map_err(|error| format!("failed to normalize sprint code: {error}"))?;
+    }
+
+    Ok(())
+}
+
+fn database_is_intact(conn: &Connection) -> Result<bool, String> {
+    match conn.query_row("PRAGMA integrity_check", [], |row| row.get::<_, String>(0)) {
+        Ok(result) => Ok(result == "ok"),
+        Err(error)
+            if matches!(
+                error.sqlite_error_code(),
+                Some(ErrorCode::DatabaseCorrupt | ErrorCode::NotADatabase)
+            ) =>
+        {
+            Ok(false)
+        }
+        Err(error) => Err(format!("failed to check database integrity: {error}")),
+    }
+}
+
+fn quarantine_db_file(db_path: &Path) -> Result<PathBuf, String> {
+    let quarantined_path = db_path.with_file_name(format!(
+        "daily-updates.corrupt-{}.sqlite",
+        Utc::now().format("%Y%m%d%H%M%S")
+    ));
+
+    fs::rename(db_path, &quarantined_path).map_err(|error| {
+        format!(
+            "unable to move corrupted database to {}: {error}",
+            quarantined_path.display()
+        )
+    })?;
+
+    for suffix in ["-journal", "-wal", "-shm"] {
+        let sidecar = PathBuf::from(format!("{}{suffix}", db_path.display()));
+        if !sidecar.exists() {
+            continue;
+        }
+
+        let quarantined_sidecar = PathBuf::from(format!("{}{suffix}", quarantined_path.display()));
+        fs::rename(&sidecar, &quarantined_sidecar).map_err(|error| {
+            format!(
+                "unable to move {} to {}: {error}",
+                sidecar.display(),
+                quarantined_sidecar.display()
+            )
+        })?;
+    }
+
+    Ok(quarantined_path)
+}
+
+fn latest_backup_path(app: &AppHandle) -> Option<PathBuf> {
+    let directory = backups_dir(app).ok()?;
+
+    fs::read_dir(directory)
+        .ok()?
+        .flatten()
+        .filter(|dir_entry| {
+            dir_entry
+                .path()
+                .extension()
+                .is_some_and(|extension| extension == "sqlite")
+        })
+        .filter_map(|dir_entry| {
+            let modified = dir_entry.metadata().ok()?.modified().ok()?;
+            Some((modified, dir_entry.path()))
+        })
+        .max_by_key(|(modified, _)| *modified)
+        .map(|(_, path)| path)
+}
+
+fn open_db(app: &AppHandle) -> Result<(Connection, Option<DbRecovery>), String> {
+    let db_path = db_file_path(app)?;
+    let mut conn = Connection::open(&db_path)
+        .map_err(|error| format!("unable to open database {}: {error}", db_path.display()))?;
+
+    let mut recovery = None;
+    if !database_is_intact(&conn)? {
+        drop(conn);
+        let quarantined_path = quarantine_db_file(&db_path)?;
+        recovery = Some(DbRecovery {
+            quarantined_path: quarantined_path.to_string_lossy().to_string(),
+            backup_path: latest_backup_path(app).map(|path| path.to_string_lossy().to_string()),
+        });
+
+        conn = Connection::open(&db_path)
+            .map_err(|error| format!("unable to create database {}: {error}", db_path.display()))?;
+    }
+
+    initialize_db(app, &mut conn)?;
+    Ok((conn, recovery))
+}
+
+fn initialize_db(app: &AppHandle, conn: &mut Connection) -> Result<(), String> {
+    init_schema(conn)?;
+    migrate_legacy_json_if_needed(app, conn)?;
+    ensure_default_categories_db(conn)?;
+    ensure_default_report_template_db(conn)?;
+    migrate_preview_category_db(conn)?;
+    ensure_sprint_codes_db(conn)?;
+    ensure_entry_seq_db(conn)?;
+    Ok(())
+}
+
+fn category_name_exists(
+    conn: &Connection,
+    name: &str,
+    excluding_id: Option<&str>,
+) -> Result<bool, String> {
+    if let Some(excluding) = excluding_id {
+        let existing = conn
+            .query_row(
+                "SELECT id FROM categories WHERE lower(name) = lower(?1) AND id <> ?2 LIMIT 1",
+                params![name, excluding],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|error| format!("failed to check category uniqueness: {error}"))?;
+
+        Ok(existing.is_some())
+    } else {
+        let existing = conn
+            .query_row(
+                "SELECT id FROM categories WHERE lower(name) = lower(?1) LIMIT 1",
+                params![name],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|error| format!("failed to check category uniqueness: {error}"))?;
+
+        Ok(existing.is_some())
+    }
+}
+
+fn category_exists(conn: &Connection, id: &str) -> Result<bool, String> {
+    let existing = conn
+        .query_row(
+            "SELECT 1 FROM categories WHERE id = ?1 LIMIT 1",
+            params![id],
+            |row| row.get::<_, i64>(0),
+        )
+        .optional()
+        .map_err(|error| format!("failed to check category existence: {error}"))?;
+
+    Ok(existing.is_some())
+}
+
+fn sprint_exists(conn: &Connection, id: &str) -> Result<bool, String> {
+    let existing = conn
+        .query_row(
+            "SELECT 1 FROM sprints WHERE id = ?1 LIMIT 1",
+            params![id],
+            |row| row.get::<_, i64>(0),
+        )
+        .optional()
+        .map_err(|error| format!("failed to check sprint existence: {error}"))?;
+
+    Ok(existing.is_some())
+}
+
+fn category_from_row(row: &Row<'_>) -> rusqlite::Result<Category> {
+    Ok(Category {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        created_at: row.get(2)?,
+        color: row.get(3)?,
+        icon: row.get(4)?,
+    })
+}
+
+fn get_setting_db(conn: &Connection, key: &str) -> Result<Option<String>, String> {
+    conn.query_row(
+        "SELECT value FROM settings WHERE key = ?1",
+        params![key],
+        |row| row.get::<_, String>(0),
+    )
+    .optional()
+    .map_err(|error| format!("failed to read setting {key}: {error}"))
+}
+
+fn set_setting_db(conn: &Connection, key: &str, value: Option<&str>) -> Result<(), String> {
+    match value {
+        Some(value) => conn.execute(
+            "INSERT INTO settings (key, value) VALUES (?1, ?2)
+             ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+            params![key, value],
+        ),
+        None => conn.execute("DELETE FROM settings WHERE key = ?1", params![key]),
+    }
+    .map_err(|error| format!("failed to save setting {key}: {error}"))?;
+
+    Ok(())
+}
+
+fn get_category_db(conn: &Connection, id: &str) -> Result<Option<Category>, String> {
+    conn.query_row(
+        "SELECT id, name, created_at, color, icon FROM categories WHERE id = ?1",
+        params![id],
+        category_from_row,
+    )
+    .optional()
+    .map_err(|error| format!("failed to read category: {error}"))
+}
+
+fn find_category_by_name_db(conn: &Connection, name: &str) -> Result<Option<Category>, String> {
+    conn.query_row(
+        "SELECT id, name, created_at, color, icon FROM categories WHERE lower(name) = lower(?1) LIMIT 1",
+        params![name],
+        category_from_row,
+    )
+    .optional()
+    .map_err(|error| format!("failed to look up category by name: {error}"))
+}
+
+fn list_categories_db(conn: &Connection) -> Result<Vec<Category>, String> {
+    let mut stmt = conn
+        .prepare_cached("SELECT id, name, created_at, color, icon FROM categories ORDER BY position, created_at")
+        .map_err(|error| format!("failed to prepare categories query: {error}"))?;
+
+    let rows = stmt
+        .query_map([], category_from_row)
+        .map_err(|error| format!("failed to query categories: {error}"))?;
+
+    let items = rows
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect categories: {error}"))?;
+
+    Ok(items)
+}
+
+fn sprint_from_row(row: &Row<'_>) -> rusqlite::Result<Sprint> {
+    Ok(Sprint {
+        id: row.get(0)?,
+        code: row.get(1)?,
+        name: row.get(2)?,
+        start_date: row.get(3)?,
+        end_date: row.get(4)?,
+        created_at: row.get(5)?,
+        archived: row.get(6)?,
+        description: row.get(7)?,
+        entry_count: row.get::<_, i64>(8)? as usize,
+    })
+}
+
+fn get_sprint_db(conn: &Connection, id: &str) -> Result<Option<Sprint>, String> {
+    conn.query_row(
+        "SELECT id, code, name, start_date, end_date, created_at, archived, description,
+                (SELECT COUNT(*) FROM entries WHERE entries.sprint_id = sprints.id)
+         FROM sprints
+         WHERE id = ?1",
+        params![id],
+        sprint_from_row,
+    )
+    .optional()
+    .map_err(|error| format!("failed to read sprint: {error}"))
+}
+
+fn list_sprints_db(conn: &Connection, include_archived: bool) -> Result<Vec<Sprint>, String> {
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT s.id, s.code, s.name, s.start_date, s.end_date, s.created_at, s.archived,
+                    s.description, COUNT(e.id)
+             FROM sprints s
+             LEFT JOIN entries e ON e.sprint_id = s.id
+             WHERE ?1 OR s.archived = 0
+             GROUP BY s.id
+             ORDER BY s.created_at",
+        )
+        .map_err(|error| format!("failed to prepare sprints query: {error}"))?;
+
+    let rows = stmt
+        .query_map(params![include_archived], sprint_from_row)
+        .map_err(|error| format!("failed to query sprints: {error}"))?;
+
+    let items = rows
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect sprints: {error}"))?;
+
+    Ok(items)
+}
+
+fn parse_sprint_sort_order(raw: Option<&str>) -> Result<(SprintSortKey, bool), String> {
+    let value = raw.map(str::trim).unwrap_or_default().to_ascii_lowercase();
+    let (name, descending) = if let Some(name) = value.strip_suffix("_desc") {
+        (name, true)
+    } else {
+        (value.strip_suffix("_asc").unwrap_or(&value), false)
+    };
+
+    let key =
+        match name {
+            "" | "created" => SprintSortKey::Created,
+            "start_date" => SprintSortKey::StartDate,
+            "code" => SprintSortKey::Code,
+            _ => return Err(
+                "sort_order must be created, start_date, or code (optionally with _asc or _desc)"
+                    .to_string(),
+            ),
+        };
+
+    Ok((key, descending))
+}
+
+fn sort_sprints(sprints: &mut [Sprint], key: SprintSortKey, descending: bool) {
+    sprints.sort_by(|left, right| {
+        let ordering = match key {
+            SprintSortKey::Created => left.created_at.cmp(&right.created_at),
+            SprintSortKey::StartDate => left
+                .start_date
+                .cmp(&right.start_date)
+                .then_with(|| left.created_at.cmp(&right.created_at)),
+            SprintSortKey::Code => match (sprint_number(&left.code), sprint_number(&right.code)) {
+                (Some(left_number), Some(right_number)) => left_number.cmp(&right_number),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => left.code.cmp(&right.code),
+            },
+        };
+
+        if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+}
+
+fn entry_from_row(row: &Row<'_>) -> rusqlite::Result<DailyEntry> {
+    Ok(DailyEntry {
+        id: row.get(0)?,
+        sprint_id: row.get(1)?,
+        date: row.get(2)?,
+        category_id: row.get(3)?,
+        title: row.get(4)?,
+        details: row.get(5)?,
+        created_at: row.get(6)?,
+        updated_at: row.get(7)?,
+        link: row.get(8)?,
+        seq: row.get(9)?,
+        tags: Vec::new(),
+    })
+}
+
+fn get_entry_db(conn: &Connection, id: &str) -> Result<Option<DailyEntry>, String> {
+    let entry = conn
+        .query_row(
+            "SELECT id, sprint_id, date, category_id, title, details, created_at, updated_at, link, seq
+             FROM entries
+             WHERE id = ?1",
+            params![id],
+            entry_from_row,
+        )
+        .optional()
+        .map_err(|error| format!("failed to read entry: {error}"))?;
+
+    let Some(mut entry) = entry else {
+        return Ok(None);
+    };
+
+    entry.tags = entry_tags_db(conn, id)?;
+    Ok(Some(entry))
+}
+
+fn entry_tags_db(conn: &Connection, entry_id: &str) -> Result<Vec<String>, String> {
+    let mut stmt = conn
+        .prepare_cached("SELECT tag FROM entry_tags WHERE entry_id = ?1 ORDER BY tag")
+        .map_err(|error| format!("failed to prepare entry tags query: {error}"))?;
+
+    let rows = stmt
+        .query_map(params![entry_id], |row| row.get::<_, String>(0))
+        .map_err(|error| format!("failed to query entry tags: {error}"))?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect entry tags: {error}"))
+}
+
+fn attach_entry_tags_db(
+    conn: &Connection,
+    sprint_id: Option<&str>,
+    entries: &mut [DailyEntry],
+) -> Result<(), String> {
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT t.entry_id, t.tag
+             FROM entry_tags t
+             JOIN entries e ON e.id = t.entry_id
+             WHERE ?1 IS NULL OR e.sprint_id = ?1
+             ORDER BY t.tag",
+        )
+        .map_err(|error| format!("failed to prepare entry tags query: {error}"))?;
+
+    let rows = stmt
+        .query_map(params![sprint_id], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })
+        .map_err(|error| format!("failed to query entry tags: {error}"))?;
+
+    let mut tags_by_entry = HashMap::<String, Vec<String>>::new();
+    for row in rows {
+        let (entry_id, tag) = row.map_err(|error| format!("failed to read entry tag: {error}"))?;
+        tags_by_entry.entry(entry_id).or_default().push(tag);
+    }
+
+    for entry in entries {
+        if let Some(tags) = tags_by_entry.remove(&entry.id) {
+            entry.tags = tags;
+        }
+    }
+
+    Ok(())
+}
+
+fn list_entries_for_sprint_db(
+    conn: &Connection,
+    sprint_id: &str,
+    from_date: Option<&str>,
+    to_date: Option<&str>,
+) -> Result<Vec<DailyEntry>, String> {
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT id, sprint_id, date, category_id, title, details, created_at, updated_at, link, seq
+             FROM entries
+             WHERE sprint_id = ?1
+               AND (?2 IS NULL OR date >= ?2)
+               AND (?3 IS NULL OR date <= ?3)
+             ORDER BY date, category_id, order_index, created_at",
+        )
+        .map_err(|error| format!("failed to prepare entries query: {error}"))?;
+
+    let rows = stmt
+        .query_map(params![sprint_id, from_date, to_date], entry_from_row)
+        .map_err(|error| format!("failed to query entries: {error}"))?;
+
+    let items = rows
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect entries: {error}"))?;
+
+    Ok(items)
+}
+
+fn list_all_entries_db(conn: &Connection) -> Result<Vec<DailyEntry>, String> {
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT id, sprint_id, date, category_id, title, details, created_at, updated_at, link, seq
+             FROM entries
+             ORDER BY date, category_id, order_index, created_at",
+        )
+        .map_err(|error| format!("failed to prepare entries query: {error}"))?;
+
+    let rows = stmt
+        .query_map([], entry_from_row)
+        .map_err(|error| format!("failed to query entries: {error}"))?;
+
+    let items = rows
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect entries: {error}"))?;
+
+    Ok(items)
+}
+
+fn count_entries_grouped_db(
+    conn: &Connection,
+    sprint_id: &str,
+    column: &str,
+) -> Result<Vec<(String, usize)>, String> {
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT {column}, COUNT(*) FROM entries WHERE sprint_id = ?1 GROUP BY {column} ORDER BY {column}"
+        ))
+        .map_err(|error| format!("failed to prepare entry counts query: {error}"))?;
+
+    let rows = stmt
+        .query_map(params![sprint_id], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? as usize))
+        })
+        .map_err(|error| format!("failed to query entry counts: {error}"))?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect entry counts: {error}"))
+}
+
+fn next_sprint_code_db(conn: &Connection) -> Result<String, String> {
+    let mut stmt = conn
+        .prepare("SELECT code, name FROM sprints")
+        .map_err(|error| format!("failed to prepare next sprint code query: {error}"))?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })
+        .map_err(|error| format!("failed to query sprint codes: {error}"))?;
+
+    let pairs = rows
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect sprint codes: {error}"))?;
+
+    let highest = pairs
+        .iter()
+        .filter_map(|(code, name)| sprint_number(code).or_else(|| sprint_number(name)))
+        .max()
+        .unwrap_or(0);
+
+    Ok(format_sprint_code(highest + 1))
+}
+
+#[tauri::command]
+fn list_categories(db: State<'_, DbState>) -> Result<Vec<Category>, String> {
+    let conn = db.conn()?;
+    list_categories_db(&conn)
+}
+
+#[tauri::command]
+fn list_categories_used_in_sprint(
+    db: State<'_, DbState>,
+    sprint_id: String,
+) -> Result<Vec<Category>, String> {
+    let conn = db.conn()?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT c.id, c.name, c.created_at, c.color, c.icon
+             FROM categories c
+             WHERE EXISTS (
+                 SELECT 1 FROM entries e WHERE e.category_id = c.id AND e.sprint_id = ?1
+             )
+             ORDER BY c.position, c.created_at",
+        )
+        .map_err(|error| format!("failed to prepare sprint categories query: {error}"))?;
+
+    let rows = stmt
+        .query_map(params![sprint_id], category_from_row)
+        .map_err(|error| format!("failed to query sprint categories: {error}"))?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect sprint categories: {error}"))
+}
+
+#[tauri::command]
+fn create_category(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: NewCategoryInput,
+) -> Result<Category, String> {
+    let name = input.name.trim();
+    if name.is_empty() {
+        return Err("category name is required".to_string());
+    }
+
+    let color = normalize_color(input.color)?;
+    let icon = normalize_icon(input.icon);
+
+    let conn = db.conn()?;
+    if category_name_exists(&conn, name, None)? {
+        return Err("category name already exists".to_string());
+    }
+
+    let category = Category {
+        id: format!("cat-{}-{}", slugify(name), Utc::now().timestamp_millis()),
+        name: name.to_string(),
+        created_at: now(),
+        color,
+        icon,
+    };
+
+    conn.execute(
+        "INSERT INTO categories (id, name, created_at, color, icon) VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![
+            category.id,
+            category.name,
+            category.created_at,
+            category.color,
+            category.icon
+        ],
+    )
+    .map_err(|error| format!("failed to create category: {error}"))?;
+
+    emit_data_changed(&app, "categories");
+    Ok(category)
+}
+
+#[tauri::command]
+fn ensure_category(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: EnsureCategoryInput,
+) -> Result<EnsureCategoryOutput, String> {
+    let name = input.name.trim();
+    if name.is_empty() {
+        return Err("category name is required".to_string());
+    }
+
+    let conn = db.conn()?;
+    if let Some(category) = find_category_by_name_db(&conn, name)? {
+        return Ok(EnsureCategoryOutput {
+            category,
+            created: false,
+        });
+    }
+
+    let category = Category {
+        id: format!("cat-{}-{}", slugify(name), Utc::now().timestamp_millis()),
+        name: name.to_string(),
+        created_at: now(),
+        color: None,
+        icon: None,
+    };
+
+    let inserted = conn.execute(
+        "INSERT INTO categories (id, name, created_at) VALUES (?1, ?2, ?3)",
+        params![category.id, category.name, category.created_at],
+    );
+
+    match inserted {
+        Ok(_) => {
+            emit_data_changed(&app, "categories");
+            Ok(EnsureCategoryOutput {
+                category,
+                created: true,
+            })
+        }
+        Err(error) if error.sqlite_error_code() == Some(ErrorCode::ConstraintViolation) => {
+            let category = find_category_by_name_db(&conn, name)?
+                .ok_or_else(|| format!("failed to create category: {error}"))?;
+            Ok(EnsureCategoryOutput {
+                category,
+                created: false,
+            })
+        }
+        Err(error) => Err(format!("failed to create category: {error}")),
+    }
+}
+
+#[tauri::command]
+fn update_category(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: UpdateCategoryInput,
+) -> Result<Category, String> {
+    let id = input.id.trim();
+    let name = input.name.trim();
+
+    if id.is_empty() {
+        return Err("category id is required".to_string());
+    }
+
+    if name.is_empty() {
+        return Err("category name is required".to_string());
+    }
+
+    let conn = db.conn()?;
+
+    if category_name_exists(&conn, name, Some(id))? {
+        return Err("category name already exists".to_string());
+    }
+
+    let existing = get_category_db(&conn, id)?.ok_or_else(|| "category not found".to_string())?;
+    let color = match input.color {
+        Some(value) => normalize_color(Some(value))?,
+        None => existing.color,
+    };
+    let icon = match input.icon {
+        Some(value) => normalize_icon(Some(value)),
+        None => existing.icon,
+    };
+
+    let affected = conn
+        .execute(
+            "UPDATE categories SET name = ?1, color = ?2, icon = ?3 WHERE id = ?4",
+            params![name, color, icon, id],
+        )
+        .map_err(|error| format!("failed to update category: {error}"))?;
+
+    if affected == 0 {
+        return Err("category not found".to_string());
+    }
+
+    let category =
+        get_category_db(&conn, id)?.ok_or_else(|| "failed to load updated category".to_string())?;
+
+    emit_data_changed(&app, "categories");
+    Ok(category)
+}
+
+#[tauri::command]
+fn delete_category(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: DeleteCategoryInput,
+) -> Result<(), String> {
+    let category_id = input.id.trim();
+    if category_id.is_empty() {
+        return Err("category id is required".to_string());
+    }
+
+    let conn = db.conn()?;
+
+    let total_categories: i64 = conn
+        .query_row("SELECT COUNT(*) FROM categories", [], |row| row.get(0))
+        .map_err(|error| format!("failed to count categories: {error}"))?;
+
+    if total_categories <= 1 {
+        return Err("at least one category is required".to_string());
+    }
+
+    if !category_exists(&conn, category_id)? {
+        return Err("category not found".to_string());
+    }
+
+    let used_count: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM entries WHERE category_id = ?1",
+            params![category_id],
+            |row| row.get(0),
+        )
+        .map_err(|error| format!("failed to count category usage: {error}"))?;
+
+    if used_count > 0 {
+        let replacement_from_input = input
+            .replacement_category_id
+            .as_ref()
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+            .map(|value| value.to_string());
+
+        let replacement_id = if let Some(value) = replacement_from_input {
+            value
+        } else {
+            conn.query_row(
+                "SELECT id FROM categories WHERE id <> ?1 ORDER BY position, created_at LIMIT 1",
+                params![category_id],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|error| format!("failed to find replacement category: {error}"))?
+            .ok_or_else(|| "no replacement category available".to_string())?
+        };
+
+        if replacement_id == category_id {
+            return Err("replacement category must be different".to_string());
+        }
+
+        if !category_exists(&conn, replacement_id.as_str())? {
+            return Err("replacement category not found".to_string());
+        }
+
+        reassign_category_entries_db(&conn, category_id, &replacement_id)?;
+    }
+
+    let affected = conn
+        .execute("DELETE FROM categories WHERE id = ?1", params![category_id])
+        .map_err(|error| format!("failed to delete category: {error}"))?;
+
+    if affected == 0 {
+        return Err("category not found".to_string());
+    }
+
+    emit_data_changed(&app, "categories");
+    Ok(())
+}
+
+#[tauri::command]
+fn merge_categories(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: MergeCategoriesInput,
+) -> Result<Category, String> {
+    let source_id = input.source_id.trim();
+    let target_id = input.target_id.trim();
+    if source_id.is_empty() || target_id.is_empty() {
+        return Err("source and target category ids are required".to_string());
+    }
+
+    if source_id == target_id {
+        return Err("cannot merge a category into itself".to_string());
+    }
+
+    let mut conn = db.conn()?;
+
+    if !category_exists(&conn, source_id)? {
+        return Err("source category not found".to_string());
+    }
+
+    let target = get_category_db(&conn, target_id)?
+        .ok_or_else(|| "target category not found".to_string())?;
+
+    let tx = conn
+        .transaction()
+        .map_err(|error| format!("failed to start merge transaction: {error}"))?;
+
+    reassign_category_entries_db(&tx, source_id, target_id)?;
+
+    tx.execute("DELETE FROM categories WHERE id = ?1", params![source_id])
+        .map_err(|error| format!("failed to delete merged category: {error}"))?;
+
+    tx.commit()
+        .map_err(|error| format!("failed to commit category merge: {error}"))?;
+
+    emit_data_changed(&app, "all");
+    Ok(target)
+}
+
+#[tauri::command]
+fn rekey_category(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: RekeyCategoryInput,
+) -> Result<Category, String> {
+    let old_id = input.old_id.trim();
+    let new_id = input.new_id.trim();
+    if old_id.is_empty() || new_id.is_empty() {
+        return Err("old and new category ids are required".to_string());
+    }
+
+    if old_id == new_id {
+        return Err("new category id must be different".to_string());
+    }
+
+    let mut conn = db.conn()?;
+
+    if !category_exists(&conn, old_id)? {
+        return Err("category not found".to_string());
+    }
+
+    if category_exists(&conn, new_id)? {
+        return Err("a category with that id already exists".to_string());
+    }
+
+    let tx = conn
+        .transaction()
+        .map_err(|error| format!("failed to start rekey transaction: {error}"))?;
+
+    tx.execute_batch("PRAGMA defer_foreign_keys = ON")
+        .map_err(|error| format!("failed to defer foreign keys: {error}"))?;
+
+    tx.execute(
+        "UPDATE categories SET id = ?1 WHERE id = ?2",
+        params![new_id, old_id],
+    )
+    .map_err(|error| format!("failed to rekey category: {error}"))?;
+
+    reassign_category_entries_db(&tx, old_id, new_id)?;
+
+    tx.execute(
+        "UPDATE templates SET category_id = ?1 WHERE category_id = ?2",
+        params![new_id, old_id],
+    )
+    .map_err(|error| format!("failed to reassign category templates: {error}"))?;
+
+    tx.commit()
+        .map_err(|error| format!("failed to commit category rekey: {error}"))?;
+
+    let category = get_category_db(&conn, new_id)?
+        .ok_or_else(|| "failed to fetch rekeyed category".to_string())?;
+
+    emit_data_changed(&app, "all");
+    Ok(category)
+}
+
+#[tauri::command]
+fn reorder_categories(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: ReorderCategoriesInput,
+) -> Result<Vec<Category>, String> {
+    let mut conn = db.conn()?;
+
+    let existing_ids = list_categories_db(&conn)?
+        .into_iter()
+        .map(|category| category.id)
+        .collect::<HashSet<_>>();
+    let ordered_ids = input
+        .ordered_ids
+        .iter()
+        .map(|id| id.trim().to_string())
+        .collect::<Vec<_>>();
+    let requested_ids = ordered_ids.iter().cloned().collect::<HashSet<_>>();
+
+    if requested_ids.len() != ordered_ids.len() || requested_ids != existing_ids {
+        return Err("ordered_ids must cover all categories".to_string());
+    }
+
+    let tx = conn
+        .transaction()
+        .map_err(|error| format!("failed to start reorder transaction: {error}"))?;
+
+    for (position, id) in ordered_ids.iter().enumerate() {
+        tx.execute(
+            "UPDATE categories SET position = ?1 WHERE id = ?2",
+            params![position as i64, id],
+        )
+        .map_err(|error| format!("failed to reorder category {id}: {error}"))?;
+    }
+
+    tx.commit()
+        .map_err(|error| format!("failed to commit category order: {error}"))?;
+
+    let categories = list_categories_db(&conn)?;
+    emit_data_changed(&app, "categories");
+    Ok(categories)
+}
+
+fn list_day_entries_db(
+    conn: &Connection,
+    sprint_id: &str,
+    date: &str,
+    category_id: &str,
+) -> Result<Vec<DailyEntry>, String> {
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT id, sprint_id, date, category_id, title, details, created_at, updated_at, link, seq
+             FROM entries
+             WHERE sprint_id = ?1 AND date = ?2 AND category_id = ?3
+             ORDER BY order_index, created_at",
+        )
+        .map_err(|error| format!("failed to prepare day entries query: {error}"))?;
+
+    let rows = stmt
+        .query_map(params![sprint_id, date, category_id], entry_from_row)
+        .map_err(|error| format!("failed to query day entries: {error}"))?;
+
+    let mut entries = rows
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect entries: {error}"))?;
+
+    attach_entry_tags_db(conn, Some(sprint_id), &mut entries)?;
+    Ok(entries)
+}
+
+#[tauri::command]
+fn reorder_day_entries(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: ReorderDayEntriesInput,
+) -> Result<Vec<DailyEntry>, String> {
+    let sprint_id = input.sprint_id.trim();
+    let date = input.date.trim();
+    let category_id = input.category_id.trim();
+
+    if sprint_id.is_empty() {
+        return Err("sprint id is required".to_string());
+    }
+
+    if date.is_empty() {
+        return Err("date is required".to_string());
+    }
+
+    if category_id.is_empty() {
+        return Err("category_id is required".to_string());
+    }
+
+    let mut conn = db.conn()?;
+
+    let existing_ids = list_day_entries_db(&conn, sprint_id, date, category_id)?
+        .into_iter()
+        .map(|entry| entry.id)
+        .collect::<HashSet<_>>();
+    let ordered_ids = input
+        .ordered_entry_ids
+        .iter()
+        .map(|id| id.trim().to_string())
+        .collect::<Vec<_>>();
+    let requested_ids = ordered_ids.iter().cloned().collect::<HashSet<_>>();
+
+    if requested_ids.len() != ordered_ids.len() || requested_ids != existing_ids {
+        return Err(
+            "ordered_entry_ids must cover all entries for that day and category".to_string(),
+        );
+    }
+
+    let tx = conn
+        .transaction()
+        .map_err(|error| format!("failed to start reorder transaction: {error}"))?;
+
+    for (order_index, id) in ordered_ids.iter().enumerate() {
+        tx.execute(
+            "UPDATE entries SET order_index = ?1 WHERE id = ?2",
+            params![order_index as i64, id],
+        )
+        .map_err(|error| format!("failed to reorder entry {id}: {error}"))?;
+    }
+
+    tx.commit()
+        .map_err(|error| format!("failed to commit entry order: {error}"))?;
+
+    let entries = list_day_entries_db(&conn, sprint_id, date, category_id)?;
+    emit_data_changed(&app, "entries");
+    Ok(entries)
+}
+
+#[tauri::command]
+fn get_category_usage(db: State<'_, DbState>) -> Result<Vec<CategoryUsage>, String> {
+    let conn = db.conn()?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT c.id, c.name, COUNT(e.id), MAX(e.date)
+             FROM categories c
+             LEFT JOIN entries e ON e.category_id = c.id
+             GROUP BY c.id, c.name
+             ORDER BY COUNT(e.id) ASC, lower(c.name)",
+        )
+        .map_err(|error| format!("failed to prepare category usage query: {error}"))?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(CategoryUsage {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                entry_count: row.get::<_, i64>(2)? as usize,
+                last_used_date: row.get(3)?,
+            })
+        })
+        .map_err(|error| format!("failed to query category usage: {error}"))?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect category usage: {error}"))
+}
+
+#[tauri::command]
+fn list_sprints(
+    db: State<'_, DbState>,
+    include_archived: Option<bool>,
+    sort_order: Option<String>,
+) -> Result<Vec<Sprint>, String> {
+    let (key, descending) = parse_sprint_sort_order(sort_order.as_deref())?;
+    let conn = db.conn()?;
+    let mut sprints = list_sprints_db(&conn, include_archived.unwrap_or(false))?;
+    sort_sprints(&mut sprints, key, descending);
+    Ok(sprints)
+}
+
+#[tauri::command]
+fn get_active_sprint(db: State<'_, DbState>) -> Result<Option<Sprint>, String> {
+    let conn = db.conn()?;
+
+    match pick_active_sprint_id(&conn)? {
+        Some(sprint_id) => get_sprint_db(&conn, sprint_id.as_str()),
+        None => Ok(None),
+    }
+}
+
+#[tauri::command]
+fn get_active_sprint_id(db: State<'_, DbState>) -> Result<Option<String>, String> {
+    let conn = db.conn()?;
+    pick_active_sprint_id(&conn)
+}
+
+#[tauri::command]
+fn sprints_covering_date(db: State<'_, DbState>, date: String) -> Result<Vec<Sprint>, String> {
+    let date = NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+        .map_err(|_| "date must be in YYYY-MM-DD format".to_string())?
+        .format("%Y-%m-%d")
+        .to_string();
+
+    let conn = db.conn()?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, code, name, start_date, end_date, created_at, archived, description,
+                    (SELECT COUNT(*) FROM entries WHERE entries.sprint_id = sprints.id)
+             FROM sprints
+             WHERE start_date <= ?1 AND (end_date IS NULL OR end_date >= ?1)
+             ORDER BY start_date, created_at",
+        )
+        .map_err(|error| format!("failed to prepare covering sprints query: {error}"))?;
+
+    let rows = stmt
+        .query_map(params![date], sprint_from_row)
+        .map_err(|error| format!("failed to query covering sprints: {error}"))?;
+
+    let items = rows
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect covering sprints: {error}"))?;
+
+    Ok(items)
+}
+
+fn has_logged_on_db(conn: &Connection, date: &str) -> Result<bool, String> {
+    let existing = conn
+        .query_row(
+            "SELECT 1 FROM entries WHERE date = ?1 LIMIT 1",
+            params![date],
+            |row| row.get::<_, i64>(0),
+        )
+        .optional()
+        .map_err(|error| format!("failed to check today's entries: {error}"))?;
+
+    Ok(existing.is_some())
+}
+
+#[tauri::command]
+fn has_logged_today(db: State<'_, DbState>) -> Result<bool, String> {
+    let conn = db.conn()?;
+    let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
+    has_logged_on_db(&conn, &today)
+}
+
+#[tauri::command]
+fn check_log_reminder(app: AppHandle, db: State<'_, DbState>) -> Result<bool, String> {
+    let conn = db.conn()?;
+    let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
+
+    if has_logged_on_db(&conn, &today)? {
+        return Ok(false);
+    }
+
+    let _ = app.emit("log-reminder", today);
+    Ok(true)
+}
+
+fn sprint_end_date(start_date: &str, duration_days: i64) -> Result<String, String> {
+    let parsed_start = NaiveDate::parse_from_str(start_date, "%Y-%m-%d")
+        .map_err(|_| "start_date must be in YYYY-MM-DD format".to_string())?;
+    let duration_days = validate_sprint_duration(duration_days)?;
+
+    Ok((parsed_start + Duration::days(duration_days - 1))
+        .format("%Y-%m-%d")
+        .to_string())
+}
+
+fn default_sprint_duration_db(conn: &Connection) -> Result<i64, String> {
+    let stored = get_setting_db(conn, DEFAULT_SPRINT_DURATION_SETTING)?;
+
+    Ok(stored
+        .and_then(|value| value.parse::<i64>().ok())
+        .and_then(|value| validate_sprint_duration(value).ok())
+        .unwrap_or(DEFAULT_SPRINT_DURATION_DAYS))
+}
+
+fn insert_sprint_db(conn: &Connection, sprint: &Sprint) -> Result<(), String> {
+    conn.execute(
+        "INSERT INTO sprints (id, code, name, start_date, end_date, created_at, archived, description) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        params![
+            sprint.id,
+            sprint.code,
+            sprint.name,
+            sprint.start_date,
+            sprint.end_date,
+            sprint.created_at,
+            sprint.archived,
+            sprint.description
+        ],
+    )
+    .map_err(|error| format!("failed to create sprint: {error}"))?;
+
+    Ok(())
+}
+
+fn new_sprint_db(conn: &Connection, input: NewSprintInput) -> Result<Sprint, String> {
+    let start_date = input.start_date.trim();
+    if start_date.is_empty() {
+        return Err("start_date is required".to_string());
+    }
+
+    let duration_days = match input.duration_days {
+        Some(value) => value,
+        None => default_sprint_duration_db(conn)?,
+    };
+    let calculated_end = sprint_end_date(start_date, duration_days)?;
+
+    let code = next_sprint_code_db(conn)?;
+
+    let display_name = input
+        .name
+        .as_ref()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| code.clone());
+
+    Ok(Sprint {
+        id: next_id("sprint"),
+        code,
+        name: display_name,
+        start_date: start_date.to_string(),
+        end_date: Some(calculated_end),
+        created_at: now(),
+        archived: false,
+        description: normalize_details(input.description),
+        entry_count: 0,
+    })
+}
+
+#[tauri::command]
+fn create_sprint(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: NewSprintInput,
+) -> Result<Sprint, String> {
+    let conn = db.conn()?;
+    let sprint = new_sprint_db(&conn, input)?;
+
+    insert_sprint_db(&conn, &sprint)?;
+
+    emit_data_changed(&app, "sprints");
+    Ok(sprint)
+}
+
+#[tauri::command]
+fn create_sprint_with_entry(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: NewSprintWithEntryInput,
+) -> Result<SprintWithEntryOutput, String> {
+    let mut conn = db.conn()?;
+    let mut sprint = new_sprint_db(&conn, input.sprint)?;
+
+    let tx = conn
+        .transaction()
+        .map_err(|error| format!("failed to start sprint transaction: {error}"))?;
+
+    insert_sprint_db(&tx, &sprint)?;
+    let entry = insert_entry_db(
+        &tx,
+        NewDailyEntryInput {
+            sprint_id: sprint.id.clone(),
+            date: sprint.start_date.clone(),
+            category_id: input.entry.category_id,
+            title: input.entry.title,
+            details: input.entry.details,
+            link: None,
+            reject_duplicates: None,
+        },
+    )?;
+
+    tx.commit()
+        .map_err(|error| format!("failed to commit sprint with entry: {error}"))?;
+
+    sprint.entry_count = 1;
+
+    emit_data_changed(&app, "all");
+    Ok(SprintWithEntryOutput { sprint, entry })
+}
+
+#[tauri::command]
+fn duplicate_sprint(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: DuplicateSprintInput,
+) -> Result<Sprint, String> {
+    let source_sprint_id = input.source_sprint_id.trim();
+    let start_date = input.start_date.trim();
+
+    if source_sprint_id.is_empty() {
+        return Err("source sprint id is required".to_string());
+    }
+
+    if start_date.is_empty() {
+        return Err("start_date is required".to_string());
+    }
+
+    let conn = db.conn()?;
+    let duration_days = match input.duration_days {
+        Some(value) => value,
+        None => default_sprint_duration_db(&conn)?,
+    };
+    let calculated_end = sprint_end_date(start_date, duration_days)?;
+
+    let source = get_sprint_db(&conn, source_sprint_id)?
+        .ok_or_else(|| "the selected sprint does not exist".to_string())?;
+
+    let sprint = Sprint {
+        id: next_id("sprint"),
+        code: next_sprint_code_db(&conn)?,
+        name: format!("{} (copy)", source.name),
+        start_date: start_date.to_string(),
+        end_date: Some(calculated_end),
+        created_at: now(),
+        archived: false,
+        description: source.description,
+        entry_count: 0,
+    };
+
+    insert_sprint_db(&conn, &sprint)?;
+
+    emit_data_changed(&app, "sprints");
+    Ok(sprint)
+}
+
+#[tauri::command]
+fn update_sprint_name(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: UpdateSprintNameInput,
+) -> Result<Sprint, String> {
+    let sprint_id = input.id.trim();
+    let name = input.name.trim();
+
+    if sprint_id.is_empty() {
+        return Err("sprint id is required".to_string());
+    }
+
+    if name.is_empty() {
+        return Err("sprint name is required".to_string());
+    }
+
+    let conn = db.conn()?;
+
+    let affected = conn
+        .execute(
+            "UPDATE sprints SET name = ?1 WHERE id = ?2",
+            params![name, sprint_id],
+        )
+        .map_err(|error| format!("failed to update sprint name: {error}"))?;
+
+    if affected == 0 {
+        return Err("sprint not found".to_string());
+    }
+
+    let sprint = get_sprint_db(&conn, sprint_id)?
+        .ok_or_else(|| "failed to fetch updated sprint".to_string())?;
+
+    emit_data_changed(&app, "sprints");
+    Ok(sprint)
+}
+
+#[tauri::command]
+fn update_sprint_description(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: UpdateSprintDescriptionInput,
+) -> Result<Sprint, String> {
+    let sprint_id = input.id.trim();
+    if sprint_id.is_empty() {
+        return Err("sprint id is required".to_string());
+    }
+
+    let conn = db.conn()?;
+
+    let affected = conn
+        .execute(
+            "UPDATE sprints SET description = ?1 WHERE id = ?2",
+            params![normalize_details(input.description), sprint_id],
+        )
+        .map_err(|error| format!("failed to update sprint description: {error}"))?;
+
+    if affected == 0 {
+        return Err("sprint not found".to_string());
+    }
+
+    let sprint = get_sprint_db(&conn, sprint_id)?
+        .ok_or_else(|| "failed to fetch updated sprint".to_string())?;
+
+    emit_data_changed(&app, "sprints");
+    Ok(sprint)
+}
+
+#[tauri::command]
+fn update_sprint_code(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: UpdateSprintCodeInput,
+) -> Result<Sprint, String> {
+    let sprint_id = input.id.trim();
+    let code = input.code.trim();
+
+    if sprint_id.is_empty() {
+        return Err("sprint id is required".to_string());
+    }
+
+    if code.is_empty() {
+        return Err("sprint code is required".to_string());
+    }
+
+    let conn = db.conn()?;
+
+    let affected = conn
+        .execute(
+            "UPDATE sprints SET code = ?1, code_locked = 1 WHERE id = ?2",
+            params![code, sprint_id],
+        )
+        .map_err(|error| match error.sqlite_error_code() {
+            Some(ErrorCode::ConstraintViolation) => "sprint code already exists".to_string(),
+            _ => format!("failed to update sprint code: {error}"),
+        })?;
+
+    if affected == 0 {
+        return Err("sprint not found".to_string());
+    }
+
+    let sprint = get_sprint_db(&conn, sprint_id)?
+        .ok_or_else(|| "failed to fetch updated sprint".to_string())?;
+
+    emit_data_changed(&app, "sprints");
+    Ok(sprint)
+}
+
+#[tauri::command]
+fn update_sprint_dates(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: UpdateSprintDatesInput,
+) -> Result<Sprint, String> {
+    let sprint_id = input.id.trim();
+    let start_date = input.start_date.trim();
+
+    if sprint_id.is_empty() {
+        return Err("sprint id is required".to_string());
+    }
+
+    if start_date.is_empty() {
+        return Err("start_date is required".to_string());
+    }
+
+    let parsed_start = NaiveDate::parse_from_str(start_date, "%Y-%m-%d")
+        .map_err(|_| "start_date must be in YYYY-MM-DD format".to_string())?;
+
+    let end_date = input
+        .end_date
+        .as_ref()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty() && !value.eq_ignore_ascii_case("open"));
+
+    if let Some(end) = &end_date {
+        let parsed_end = NaiveDate::parse_from_str(end, "%Y-%m-%d")
+            .map_err(|_| "end_date must be in YYYY-MM-DD format".to_string())?;
+
+        if parsed_end < parsed_start {
+            return Err("end_date must be on or after start_date".to_string());
+        }
+    }
+
+    let conn = db.conn()?;
+
+    let affected = conn
+        .execute(
+            "UPDATE sprints SET start_date = ?1, end_date = ?2 WHERE id = ?3",
+            params![start_date, end_date, sprint_id],
+        )
+        .map_err(|error| format!("failed to update sprint dates: {error}"))?;
+
+    if affected == 0 {
+        return Err("sprint not found".to_string());
+    }
+
+    let sprint = get_sprint_db(&conn, sprint_id)?
+        .ok_or_else(|| "failed to fetch updated sprint".to_string())?;
+
+    emit_data_changed(&app, "sprints");
+    Ok(sprint)
+}
+
+#[tauri::command]
+fn set_sprint_archived(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: SetSprintArchivedInput,
+) -> Result<Sprint, String> {
+    let sprint_id = input.id.trim();
+    if sprint_id.is_empty() {
+        return Err("sprint id is required".to_string());
+    }
+
+    let conn = db.conn()?;
+
+    let affected = conn
+        .execute(
+            "UPDATE sprints SET archived = ?1 WHERE id = ?2",
+            params![input.archived, sprint_id],
+        )
+        .map_err(|error| format!("failed to update sprint archive flag: {error}"))?;
+
+    if affected == 0 {
+        return Err("sprint not found".to_string());
+    }
+
+    let sprint = get_sprint_db(&conn, sprint_id)?
+        .ok_or_else(|| "failed to fetch updated sprint".to_string())?;
+
+    emit_data_changed(&app, "sprints");
+    Ok(sprint)
+}
+
+#[tauri::command]
+fn auto_archive_sprints(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: AutoArchiveSprintsInput,
+) -> Result<usize, String> {
+    let days = i64::try_from(input.older_than_days)
+        .map_err(|_| "older_than_days is too large".to_string())?;
+    let retention =
+        Duration::try_days(days).ok_or_else(|| "older_than_days is too large".to_string())?;
+    let cutoff = Local::now()
+        .date_naive()
+        .checked_sub_signed(retention)
+        .ok_or_else(|| "older_than_days is too large".to_string())?
+        .format("%Y-%m-%d")
+        .to_string();
+
+    let conn = db.conn()?;
+    let active_id = pick_active_sprint_id(&conn)?;
+
+    let archived = conn
+        .execute(
+            "UPDATE sprints
+             SET archived = 1
+             WHERE archived = 0
+               AND end_date IS NOT NULL
+               AND end_date < ?1
+               AND (?2 IS NULL OR id <> ?2)",
+            params![cutoff, active_id],
+        )
+        .map_err(|error| format!("failed to auto-archive sprints: {error}"))?;
+
+    if archived > 0 {
+        emit_data_changed(&app, "sprints");
+    }
+
+    Ok(archived)
+}
+
+#[tauri::command]
+fn delete_sprint(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: DeleteSprintInput,
+) -> Result<(), String> {
+    let sprint_id = input.id.trim();
+    if sprint_id.is_empty() {
+        return Err("sprint id is required".to_string());
+    }
+
+    let conn = db.conn()?;
+
+    if !input.force.unwrap_or(false) && pick_active_sprint_id(&conn)?.as_deref() == Some(sprint_id)
+    {
+        let code = get_sprint_db(&conn, sprint_id)?
+            .map(|sprint| sprint.code)
+            .unwrap_or_else(|| sprint_id.to_string());
+        return Err(format!("cannot delete the active sprint ({code})"));
+    }
+
+    let affected = conn
+        .execute("DELETE FROM sprints WHERE id = ?1", params![sprint_id])
+        .map_err(|error| format!("failed to delete sprint: {error}"))?;
+
+    if affected == 0 {
+        return Err("sprint not found".to_string());
+    }
+
+    emit_data_changed(&app, "sprints");
+    Ok(())
+}
+
+#[tauri::command]
+fn clear_sprint_entries(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: ClearSprintEntriesInput,
+) -> Result<usize, String> {
+    let sprint_id = input.sprint_id.trim();
+    if sprint_id.is_empty() {
+        return Err("sprint id is required".to_string());
+    }
+
+    let mut conn = db.conn()?;
+    if !sprint_exists(&conn, sprint_id)? {
+        return Err("the selected sprint does not exist".to_string());
+    }
+
+    let tx = conn
+        .transaction()
+        .map_err(|error| format!("failed to start clear transaction: {error}"))?;
+
+    let count: i64 = tx
+        .query_row(
+            "SELECT COUNT(*) FROM entries WHERE sprint_id = ?1",
+            params![sprint_id],
+            |row| row.get(0),
+        )
+        .map_err(|error| format!("failed to count sprint entries: {error}"))?;
+
+    if count as usize != input.confirm_count {
+        return Err("confirm_count mismatch".to_string());
+    }
+
+    let deleted = tx
+        .execute(
+            "DELETE FROM entries WHERE sprint_id = ?1",
+            params![sprint_id],
+        )
+        .map_err(|error| format!("failed to clear sprint entries: {error}"))?;
+
+    tx.commit()
+        .map_err(|error| format!("failed to commit sprint clear: {error}"))?;
+
+    emit_data_changed(&app, "entries");
+    Ok(deleted)
+}
+
+#[tauri::command]
+fn list_entries_for_sprint(
+    db: State<'_, DbState>,
+    sprint_id: String,
+    from_date: Option<String>,
+    to_date: Option<String>,
+) -> Result<Vec<DailyEntry>, String> {
+    let conn = db.conn()?;
+    let mut entries = list_entries_for_sprint_db(
+        &conn,
+        sprint_id.as_str(),
+        from_date.as_deref(),
+        to_date.as_deref(),
+    )?;
+    attach_entry_tags_db(&conn, Some(sprint_id.as_str()), &mut entries)?;
+    Ok(entries)
+}
+
+#[tauri::command]
+fn list_entries_by_date_range(
+    db: State<'_, DbState>,
+    input: DateRangeEntriesInput,
+) -> Result<Vec<DailyEntry>, String> {
+    let from_date = input.from_date.trim();
+    let to_date = input.to_date.trim();
+
+    let parsed_from = NaiveDate::parse_from_str(from_date, "%Y-%m-%d")
+        .map_err(|_| "from_date must be in YYYY-MM-DD format".to_string())?;
+    let parsed_to = NaiveDate::parse_from_str(to_date, "%Y-%m-%d")
+        .map_err(|_| "to_date must be in YYYY-MM-DD format".to_string())?;
+
+    if parsed_to < parsed_from {
+        return Err("to_date must be on or after from_date".to_string());
+    }
+
+    let category_set = input
+        .categories
+        .filter(|categories| !categories.is_empty())
+        .map(|categories| categories.into_iter().collect::<HashSet<_>>());
+
+    let conn = db.conn()?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, sprint_id, date, category_id, title, details, created_at, updated_at, link, seq
+             FROM entries
+             WHERE date >= ?1 AND date <= ?2
+             ORDER BY date, category_id, order_index, created_at",
+        )
+        .map_err(|error| format!("failed to prepare date range query: {error}"))?;
+
+    let rows = stmt
+        .query_map(params![from_date, to_date], entry_from_row)
+        .map_err(|error| format!("failed to query entries by date range: {error}"))?;
+
+    let mut entries = rows
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect entries: {error}"))?;
+
+    if let Some(set) = &category_set {
+        entries.retain(|entry| set.contains(&entry.category_id));
+    }
+
+    attach_entry_tags_db(&conn, None, &mut entries)?;
+    Ok(entries)
+}
+
+#[tauri::command]
+fn list_entries_by_category(
+    db: State<'_, DbState>,
+    input: CategoryEntriesInput,
+) -> Result<Vec<DailyEntry>, String> {
+    let sprint_id = input.sprint_id.trim();
+    let category_id = input.category_id.trim();
+
+    if sprint_id.is_empty() {
+        return Err("sprint id is required".to_string());
+    }
+
+    if category_id.is_empty() {
+        return Err("category_id is required".to_string());
+    }
+
+    let conn = db.conn()?;
+
+    if !category_exists(&conn, category_id)? {
+        return Err("the selected category does not exist".to_string());
+    }
+
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT id, sprint_id, date, category_id, title, details, created_at, updated_at, link, seq
+             FROM entries
+             WHERE sprint_id = ?1 AND category_id = ?2
+             ORDER BY date, order_index, created_at",
+        )
+        .map_err(|error| format!("failed to prepare category entries query: {error}"))?;
+
+    let rows = stmt
+        .query_map(params![sprint_id, category_id], entry_from_row)
+        .map_err(|error| format!("failed to query entries by category: {error}"))?;
+
+    let mut entries = rows
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect entries: {error}"))?;
+
+    attach_entry_tags_db(&conn, Some(sprint_id), &mut entries)?;
+    Ok(entries)
+}
+
+#[tauri::command]
+fn list_entries_paged(
+    db: State<'_, DbState>,
+    input: ListEntriesInput,
+) -> Result<EntriesPage, String> {
+    let limit = input.limit.unwrap_or(50) as i64;
+    let offset = input.offset.unwrap_or(0) as i64;
+
+    let conn = db.conn()?;
+
+    let total_count: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM entries WHERE sprint_id = ?1",
+            params![input.sprint_id],
+            |row| row.get(0),
+        )
+        .map_err(|error| format!("failed to count entries: {error}"))?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, sprint_id, date, category_id, title, details, created_at, updated_at, link, seq
+             FROM entries
+             WHERE sprint_id = ?1
+             ORDER BY date, category_id, order_index, created_at
+             LIMIT ?2 OFFSET ?3",
+        )
+        .map_err(|error| format!("failed to prepare entries query: {error}"))?;
+
+    let rows = stmt
+        .query_map(params![input.sprint_id, limit, offset], entry_from_row)
+        .map_err(|error| format!("failed to query entries: {error}"))?;
+
+    let mut entries = rows
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect entries: {error}"))?;
+    attach_entry_tags_db(&conn, Some(input.sprint_id.as_str()), &mut entries)?;
+
+    Ok(EntriesPage {
+        entries,
+        total_count: total_count as usize,
+    })
+}
+
+#[tauri::command]
+fn get_entry(db: State<'_, DbState>, id: String) -> Result<DailyEntry, String> {
+    let conn = db.conn()?;
+    get_entry_db(&conn, id.trim())?.ok_or_else(|| "entry not found".to_string())
+}
+
+fn insert_entry_db(conn: &Connection, input: NewDailyEntryInput) -> Result<DailyEntry, String> {
+    let title = input.title.trim();
+
+    if title.is_empty() {
+        return Err("title is required".to_string());
+    }
+
+    validate_title_length(conn, title)?;
+
+    if input.date.trim().is_empty() {
+        return Err("date is required".to_string());
+    }
+
+    if input.category_id.trim().is_empty() {
+        return Err("category_id is required".to_string());
+    }
+
+    let link = normalize_link(input.link)?;
+
+    if !sprint_exists(conn, input.sprint_id.as_str())? {
+        return Err("the selected sprint does not exist".to_string());
+    }
+
+    if !category_exists(conn, input.category_id.as_str())? {
+        return Err("the selected category does not exist".to_string());
+    }
+
+    if input.reject_duplicates.unwrap_or(false) {
+        let duplicate = conn
+            .query_row(
+                "SELECT 1 FROM entries
+                 WHERE sprint_id = ?1 AND date = ?2 AND category_id = ?3 AND lower(title) = lower(?4)
+                 LIMIT 1",
+                params![input.sprint_id, input.date, input.category_id, title],
+                |row| row.get::<_, i64>(0),
+            )
+            .optional()
+            .map_err(|error| format!("failed to check for duplicate entry: {error}"))?;
+
+        if duplicate.is_some() {
+            return Err("duplicate entry".to_string());
+        }
+    }
+
+    let seq = next_entry_seq_db(conn, input.sprint_id.as_str())?;
+    let created_at = now();
+    let entry = DailyEntry {
+        id: next_id("entry"),
+        sprint_id: input.sprint_id,
+        date: input.date,
+        category_id: input.category_id,
+        title: title.to_string(),
+        details: normalize_details(input.details),
+        created_at: created_at.clone(),
+        updated_at: created_at,
+        link,
+        seq,
+        tags: Vec::new(),
+    };
+
+    conn.execute(
+        "INSERT INTO entries (id, sprint_id, date, category_id, title, details, created_at, updated_at, link, seq, order_index)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10,
+                 (SELECT COALESCE(MAX(order_index) + 1, 0) FROM entries WHERE sprint_id = ?2 AND date = ?3 AND category_id = ?4))",
+        params![
+            entry.id,
+            entry.sprint_id,
+            entry.date,
+            entry.category_id,
+            entry.title,
+            entry.details,
+            entry.created_at,
+            entry.updated_at,
+            entry.link,
+            entry.seq
+        ],
+    )
+    .map_err(|error| format!("failed to add entry: {error}"))?;
+
+    Ok(entry)
+}
+
+fn add_entry_db(conn: &Connection, input: NewDailyEntryInput) -> Result<DailyEntry, String> {
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|error| format!("failed to start entry transaction: {error}"))?;
+
+    let entry = insert_entry_db(&tx, input)?;
+
+    tx.commit()
+        .map_err(|error| format!("failed to commit entry: {error}"))?;
+
+    Ok(entry)
+}
+
+#[tauri::command]
+fn add_daily_entry(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: NewDailyEntryInput,
+) -> Result<DailyEntry, String> {
+    let conn = db.conn()?;
+    let entry = add_entry_db(&conn, input)?;
+
+    emit_data_changed(&app, "entries");
+    Ok(entry)
+}
+
+#[tauri::command]
+fn add_daily_entries_batch(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: NewEntriesBatchInput,
+) -> Result<BatchResult, String> {
+    if input.entries.is_empty() {
+        return Err("at least one entry is required".to_string());
+    }
+
+    let mut conn = db.conn()?;
+    let tx = conn
+        .transaction()
+        .map_err(|error| format!("failed to start batch transaction: {error}"))?;
+
+    let mut ids = Vec::with_capacity(input.entries.len());
+    for (index, entry) in input.entries.into_iter().enumerate() {
+        let entry =
+            insert_entry_db(&tx, entry).map_err(|error| format!("entry {index}: {error}"))?;
+        ids.push(entry.id);
+    }
+
+    tx.commit()
+        .map_err(|error| format!("failed to commit entry batch: {error}"))?;
+
+    emit_data_changed(&app, "entries");
+    Ok(BatchResult {
+        inserted: ids.len(),
+        ids,
+    })
+}
+
+#[tauri::command]
+fn update_daily_entry(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: UpdateDailyEntryInput,
+) -> Result<DailyEntry, String> {
+    let conn = db.conn()?;
+    let entry = update_entry_db(&conn, input)?;
+
+    emit_data_changed(&app, "entries");
+    Ok(entry)
+}
+
+fn update_entry_db(conn: &Connection, input: UpdateDailyEntryInput) -> Result<DailyEntry, String> {
+    let entry_id = input.id.trim();
+    let date = input.date.trim();
+    let category_id = input.category_id.trim();
+    let title = input.title.trim();
+
+    if entry_id.is_empty() {
+        return Err("entry id is required".to_string());
+    }
+
+    if title.is_empty() {
+        return Err("title is required".to_string());
+    }
+
+    if date.is_empty() {
+        return Err("date is required".to_string());
+    }
+
+    if category_id.is_empty() {
+        return Err("category_id is required".to_string());
+    }
+
+    let normalized_link = normalize_link(input.link)?;
+
+    validate_title_length(conn, title)?;
+
+    let existing = get_entry_db(conn, entry_id)?.ok_or_else(|| "entry not found".to_string())?;
+
+    if !sprint_exists(conn, existing.sprint_id.as_str())? {
+        return Err("the selected sprint does not exist".to_string());
+    }
+
+    if !category_exists(conn, category_id)? {
+        return Err("the selected category does not exist".to_string());
+    }
+
+    let normalized_details = normalize_details(input.details);
+
+    let order_index = if existing.date != date || existing.category_id != category_id {
+        Some(next_order_index_db(
+            conn,
+            &existing.sprint_id,
+            date,
+            category_id,
+        )?)
+    } else {
+        None
+    };
+
+    let affected = conn
+        .execute(
+            "UPDATE entries
+             SET date = ?1, category_id = ?2, title = ?3, details = ?4, link = ?5, updated_at = ?6,
+                 order_index = COALESCE(?7, order_index)
+             WHERE id = ?8",
+            params![
+                date,
+                category_id,
+                title,
+                normalized_details,
+                normalized_link,
+                now(),
+                order_index,
+                entry_id
+            ],
+        )
+        .map_err(|error| format!("failed to update entry: {error}"))?;
+
+    if affected == 0 {
+        return Err("entry not found".to_string());
+    }
+
+    get_entry_db(conn, entry_id)?.ok_or_else(|| "failed to fetch updated entry".to_string())
+}
+
+#[tauri::command]
+fn delete_daily_entry(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: DeleteDailyEntryInput,
+) -> Result<DailyEntry, String> {
+    let entry_id = input.id.trim();
+    if entry_id.is_empty() {
+        return Err("entry id is required".to_string());
+    }
+
+    let conn = db.conn()?;
+    let entry = get_entry_db(&conn, entry_id)?.ok_or_else(|| "entry not found".to_string())?;
+
+    let affected = conn
+        .execute("DELETE FROM entries WHERE id = ?1", params![entry_id])
+        .map_err(|error| format!("failed to delete entry: {error}"))?;
+
+    if affected == 0 {
+        return Err("entry not found".to_string());
+    }
+
+    emit_data_changed(&app, "entries");
+    Ok(entry)
+}
+
+fn parse_markdown_entries(markdown: &str) -> (Vec<MarkdownImportItem>, usize) {
+    let mut items = Vec::new();
+    let mut skipped = 0;
+    let mut current_date: Option<String> = None;
+    let mut current_category: Option<String> = None;
+    let mut in_day_section = false;
+
+    for line in markdown.lines() {
+        let line = line.trim();
+
+        if let Some(header) = line.strip_prefix("### ") {
+            let label = header.trim();
+            current_category = if label.is_empty() {
+                None
+            } else {
+                Some(label.to_string())
+            };
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix("## ") {
+            let header = header.trim();
+            in_day_section = true;
+            current_category = None;
+            current_date = NaiveDate::parse_from_str(header, "%Y-%m-%d")
+                .ok()
+                .map(|date| date.format("%Y-%m-%d").to_string());
+            continue;
+        }
+
+        let Some(bullet) = line.strip_prefix("- ") else {
+            continue;
+        };
+
+        if !in_day_section {
+            continue;
+        }
+
+        let (Some(date), Some(category_label)) = (&current_date, &current_category) else {
+            skipped += 1;
+            continue;
+        };
+
+        let (title, details) = match bullet.split_once(" - ") {
+            Some((title, details)) => (title.trim(), normalize_details(Some(details.to_string()))),
+            None => (bullet.trim(), None),
+        };
+
+        if title.is_empty() {
+            skipped += 1;
+            continue;
+        }
+
+        items.push(MarkdownImportItem {
+            date: date.clone(),
+            category_label: category_label.clone(),
+            title: title.to_string(),
+            details,
+        });
+    }
+
+    (items, skipped)
+}
+
+struct ImportCategories {
+    ids: HashSet<String>,
+    by_name: HashMap<String, String>,
+    created: usize,
+}
+
+impl ImportCategories {
+    fn load(conn: &Connection) -> Result<Self, String> {
+        let categories = list_categories_db(conn)?;
+        Ok(Self {
+            ids: categories
+                .iter()
+                .map(|category| category.id.clone())
+                .collect(),
+            by_name: categories
+                .into_iter()
+                .map(|category| (category.name.to_lowercase(), category.id))
+                .collect(),
+            created: 0,
+        })
+    }
+
+    fn resolve(
+        &mut self,
+        conn: &Connection,
+        label: &str,
+        created_at: &str,
+    ) -> Result<String, String> {
+        if let Some(id) = self.by_name.get(&label.to_lowercase()) {
+            return Ok(id.clone());
+        }
+
+        if self.ids.contains(label) {
+            return Ok(label.to_string());
+        }
+
+        let id = slugify(label);
+        let name = if id == label {
+            humanize_category_id(label)
+        } else {
+            label.to_string()
+        };
+
+        if let Some(existing) = self.by_name.get(&name.to_lowercase()) {
+            return Ok(existing.clone());
+        }
+
+        if self.ids.contains(&id) {
+            return Ok(id);
+        }
+
+        conn.execute(
+            "INSERT INTO categories (id, name, created_at) VALUES (?1, ?2, ?3)",
+            params![id, name, created_at],
+        )
+        .map_err(|error| format!("failed to create category {name}: {error}"))?;
+
+        self.created += 1;
+        self.ids.insert(id.clone());
+        self.by_name.insert(name.to_lowercase(), id.clone());
+        Ok(id)
+    }
+}
+
+#[tauri::command]
+fn import_entries_markdown(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: ImportMarkdownInput,
+) -> Result<ImportMarkdownOutput, String> {
+    let mut conn = db.conn()?;
+
+    if !sprint_exists(&conn, input.sprint_id.as_str())? {
+        return Err("the selected sprint does not exist".to_string());
+    }
+
+    let (items, skipped) = parse_markdown_entries(&input.markdown);
+
+    let mut categories = ImportCategories::load(&conn)?;
+
+    let tx = conn
+        .transaction()
+        .map_err(|error| format!("failed to start import transaction: {error}"))?;
+
+    let mut imported = 0;
+    let created_at = now();
+    let id_prefix = next_id("entry-md");
+
+    for (index, item) in items.into_iter().enumerate() {
+        let category_id = categories.resolve(&tx, &item.category_label, &created_at)?;
+
+        tx.execute(
+            "INSERT INTO entries (id, sprint_id, date, category_id, title, details, created_at, updated_at, seq, order_index)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7, ?8,
+                     (SELECT COALESCE(MAX(order_index) + 1, 0) FROM entries WHERE sprint_id = ?2 AND date = ?3 AND category_id = ?4))",
+            params![
+                format!("{id_prefix}-{index}"),
+                input.sprint_id,
+                item.date,
+                category_id,
+                item.title,
+                item.details,
+                created_at,
+                next_entry_seq_db(&tx, input.sprint_id.as_str())?
+            ],
+        )
+        .map_err(|error| format!("failed to import entry: {error}"))?;
+
+        imported += 1;
+    }
+
+    tx.commit()
+        .map_err(|error| format!("failed to commit markdown import: {error}"))?;
+
+    let created_categories = categories.created;
+    if imported > 0 {
+        emit_data_changed(
+            &app,
+            if created_categories > 0 {
+                "all"
+            } else {
+                "entries"
+            },
+        );
+    }
+
+    Ok(ImportMarkdownOutput {
+        imported,
+        skipped,
+        created_categories,
+    })
+}
+
+fn parse_csv_rows(csv: &str) -> Vec<(usize, Result<Vec<String>, String>)> {
+    let mut rows = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut row_line = 1;
+    let mut chars = csv.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if in_quotes {
+            match ch {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                '\n' => {
+                    line += 1;
+                    field.push(ch);
+                }
+                _ => field.push(ch),
+            }
+            continue;
+        }
+
+        match ch {
+            '"' if field.is_empty() => in_quotes = true,
+            ',' => fields.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                fields.push(std::mem::take(&mut field));
+                rows.push((row_line, Ok(std::mem::take(&mut fields))));
+                line += 1;
+                row_line = line;
+            }
+            _ => field.push(ch),
+        }
+    }
+
+    if in_quotes {
+        rows.push((row_line, Err("unterminated quoted field".to_string())));
+    } else if !field.is_empty() || !fields.is_empty() {
+        fields.push(field);
+        rows.push((row_line, Ok(fields)));
+    }
+
+    rows
+        .into_iter()
+        .filter(|(_, row)| {
+            !matches!(row, Ok(fields) if fields.iter().all(|value| value.trim().is_empty()))
+        })
+        .collect()
+}
+
+fn parse_csv_entry(conn: &Connection, fields: &[String]) -> Result<MarkdownImportItem, String> {
+    if fields.len() < 3 || fields.len() > 4 {
+        return Err(format!("expected 3 or 4 columns, found {}", fields.len()));
+    }
+
+    let date = NaiveDate::parse_from_str(fields[0].trim(), "%Y-%m-%d")
+        .map_err(|_| format!("invalid date: {}", fields[0].trim()))?
+        .format("%Y-%m-%d")
+        .to_string();
+
+    let category_label = fields[1].trim();
+    if category_label.is_empty() {
+        return Err("category is required".to_string());
+    }
+
+    let title = fields[2].trim();
+    if title.is_empty() {
+        return Err("title is required".to_string());
+    }
+    validate_title_length(conn, title)?;
+
+    Ok(MarkdownImportItem {
+        date,
+        category_label: category_label.to_string(),
+        title: title.to_string(),
+        details: normalize_details(fields.get(3).cloned()),
+    })
+}
+
+#[tauri::command]
+fn import_entries_csv(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: ImportCsvInput,
+) -> Result<ImportCsvOutput, String> {
+    let mut conn = db.conn()?;
+
+    if !sprint_exists(&conn, input.sprint_id.as_str())? {
+        return Err("the selected sprint does not exist".to_string());
+    }
+
+    let rows = parse_csv_rows(&input.csv);
+    let has_header = matches!(rows.first(), Some((_, Ok(fields)))
+        if fields.first().is_some_and(|value| value.trim().eq_ignore_ascii_case("date")));
+
+    let mut categories = ImportCategories::load(&conn)?;
+
+    let tx = conn
+        .transaction()
+        .map_err(|error| format!("failed to start import transaction: {error}"))?;
+
+    let mut imported = 0;
+    let mut errors = Vec::new();
+    let created_at = now();
+    let id_prefix = next_id("entry-csv");
+
+    for (line, fields) in rows.into_iter().skip(usize::from(has_header)) {
+        let item = match fields.and_then(|fields| parse_csv_entry(&tx, &fields)) {
+            Ok(item) => item,
+            Err(error) => {
+                errors.push(format!("line {line}: {error}"));
+                continue;
+            }
+        };
+
+        let category_id = categories.resolve(&tx, &item.category_label, &created_at)?;
+
+        tx.execute(
+            "INSERT INTO entries (id, sprint_id, date, category_id, title, details, created_at, updated_at, seq, order_index)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7, ?8,
+                     (SELECT COALESCE(MAX(order_index) + 1, 0) FROM entries WHERE sprint_id = ?2 AND date = ?3 AND category_id = ?4))",
+            params![
+                format!("{id_prefix}-{line}"),
+                input.sprint_id,
+                item.date,
+                category_id,
+                item.title,
+                item.details,
+                created_at,
+                next_entry_seq_db(&tx, input.sprint_id.as_str())?
+            ],
+        )
+        .map_err(|error| format!("failed to import entry: {error}"))?;
+
+        imported += 1;
+    }
+
+    tx.commit()
+        .map_err(|error| format!("failed to commit csv import: {error}"))?;
+
+    let created_categories = categories.created;
+    if imported > 0 {
+        emit_data_changed(
+            &app,
+            if created_categories > 0 {
+                "all"
+            } else {
+                "entries"
+            },
+        );
+    }
+
+    Ok(ImportCsvOutput {
+        imported,
+        skipped: errors.len(),
+        created_categories,
+        errors,
+    })
+}
+
+#[tauri::command]
+fn move_entry_to_sprint(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: MoveEntryInput,
+) -> Result<MoveEntryOutput, String> {
+    let entry_id = input.entry_id.trim();
+    let target_sprint_id = input.target_sprint_id.trim();
+
+    if entry_id.is_empty() {
+        return Err("entry id is required".to_string());
+    }
+
+    if target_sprint_id.is_empty() {
+        return Err("target sprint id is required".to_string());
+    }
+
+    let conn = db.conn()?;
+
+    let existing = get_entry_db(&conn, entry_id)?.ok_or_else(|| "entry not found".to_string())?;
+
+    let target = get_sprint_db(&conn, target_sprint_id)?
+        .ok_or_else(|| "the selected sprint does not exist".to_string())?;
+
+    let affected = conn
+        .execute(
+            "UPDATE entries SET sprint_id = ?1, seq = ?2, order_index = ?3, updated_at = ?4 WHERE id = ?5",
+            params![
+                target.id,
+                next_entry_seq_db(&conn, target.id.as_str())?,
+                next_order_index_db(&conn, &target.id, &existing.date, &existing.category_id)?,
+                now(),
+                entry_id
+            ],
+        )
+        .map_err(|error| format!("failed to move entry: {error}"))?;
+
+    if affected == 0 {
+        return Err("entry not found".to_string());
+    }
+
+    let entry =
+        get_entry_db(&conn, entry_id)?.ok_or_else(|| "failed to fetch moved entry".to_string())?;
+    let date_outside_window = !within_range(
+        &entry.date,
+        &Some(target.start_date.clone()),
+        &target.end_date,
+    );
+
+    emit_data_changed(&app, "entries");
+    Ok(MoveEntryOutput {
+        entry,
+        date_outside_window,
+    })
+}
+
+#[tauri::command]
+fn set_entry_date(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: SetEntryDateInput,
+) -> Result<SetEntryDateOutput, String> {
+    let entry_id = input.entry_id.trim();
+    if entry_id.is_empty() {
+        return Err("entry id is required".to_string());
+    }
+
+    let date = NaiveDate::parse_from_str(input.date.trim(), "%Y-%m-%d")
+        .map_err(|_| "date must be in YYYY-MM-DD format".to_string())?
+        .format("%Y-%m-%d")
+        .to_string();
+
+    let conn = db.conn()?;
+    let output = set_entry_date_db(&conn, entry_id, &date)?;
+
+    emit_data_changed(&app, "entries");
+    Ok(output)
+}
+
+fn set_entry_date_db(
+    conn: &Connection,
+    entry_id: &str,
+    date: &str,
+) -> Result<SetEntryDateOutput, String> {
+    let existing = get_entry_db(conn, entry_id)?.ok_or_else(|| "entry not found".to_string())?;
+
+    let affected = conn
+        .execute(
+            "UPDATE entries
+             SET date = ?1,
+                 order_index = CASE WHEN date = ?1 THEN order_index ELSE ?2 END,
+                 updated_at = ?3
+             WHERE id = ?4",
+            params![
+                date,
+                next_order_index_db(conn, &existing.sprint_id, date, &existing.category_id)?,
+                now(),
+                entry_id
+            ],
+        )
+        .map_err(|error| format!("failed to update entry date: {error}"))?;
+
+    if affected == 0 {
+        return Err("entry not found".to_string());
+    }
+
+    let entry =
+        get_entry_db(conn, entry_id)?.ok_or_else(|| "failed to fetch updated entry".to_string())?;
+    let sprint = get_sprint_db(conn, &entry.sprint_id)?
+        .ok_or_else(|| "the selected sprint does not exist".to_string())?;
+    let within_sprint_window =
+        within_range(&entry.date, &Some(sprint.start_date), &sprint.end_date);
+
+    Ok(SetEntryDateOutput {
+        entry,
+        within_sprint_window,
+    })
+}
+
+#[tauri::command]
+fn duplicate_entry(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: DuplicateEntryInput,
+) -> Result<DailyEntry, String> {
+    let entry_id = input.entry_id.trim();
+    if entry_id.is_empty() {
+        return Err("entry id is required".to_string());
+    }
+
+    let new_date = match input
+        .new_date
+        .as_deref()
+        .map(str::trim)
+        .filter(|date| !date.is_empty())
+    {
+        Some(date) => Some(
+            NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map_err(|_| "new_date must be in YYYY-MM-DD format".to_string())?
+                .format("%Y-%m-%d")
+                .to_string(),
+        ),
+        None => None,
+    };
+
+    let conn = db.conn()?;
+    let source = get_entry_db(&conn, entry_id)?.ok_or_else(|| "entry not found".to_string())?;
+
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|error| format!("failed to start duplicate transaction: {error}"))?;
+
+    let mut entry = insert_entry_db(
+        &tx,
+        NewDailyEntryInput {
+            sprint_id: source.sprint_id,
+            date: new_date.unwrap_or(source.date),
+            category_id: source.category_id,
+            title: source.title,
+            details: source.details,
+            link: source.link,
+            reject_duplicates: None,
+        },
+    )?;
+
+    for tag in &source.tags {
+        tx.execute(
+            "INSERT OR IGNORE INTO entry_tags (entry_id, tag) VALUES (?1, ?2)",
+            params![entry.id, tag],
+        )
+        .map_err(|error| format!("failed to copy entry tag: {error}"))?;
+    }
+    entry.tags = source.tags;
+
+    tx.commit()
+        .map_err(|error| format!("failed to commit duplicated entry: {error}"))?;
+
+    emit_data_changed(&app, "entries");
+    Ok(entry)
+}
+
+#[tauri::command]
+fn add_entry_tag(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: EntryTagInput,
+) -> Result<Vec<String>, String> {
+    let entry_id = input.entry_id.trim();
+    if entry_id.is_empty() {
+        return Err("entry id is required".to_string());
+    }
+
+    let tag = normalize_tag(&input.tag).ok_or_else(|| "tag is required".to_string())?;
+
+    let conn = db.conn()?;
+
+    if get_entry_db(&conn, entry_id)?.is_none() {
+        return Err("entry not found".to_string());
+    }
+
+    conn.execute(
+        "INSERT OR IGNORE INTO entry_tags (entry_id, tag) VALUES (?1, ?2)",
+        params![entry_id, tag],
+    )
+    .map_err(|error| format!("failed to add entry tag: {error}"))?;
+
+    let tags = entry_tags_db(&conn, entry_id)?;
+    emit_data_changed(&app, "entries");
+    Ok(tags)
+}
+
+#[tauri::command]
+fn remove_entry_tag(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: EntryTagInput,
+) -> Result<Vec<String>, String> {
+    let entry_id = input.entry_id.trim();
+    if entry_id.is_empty() {
+        return Err("entry id is required".to_string());
+    }
+
+    let tag = normalize_tag(&input.tag).ok_or_else(|| "tag is required".to_string())?;
+
+    let conn = db.conn()?;
+
+    if get_entry_db(&conn, entry_id)?.is_none() {
+        return Err("entry not found".to_string());
+    }
+
+    conn.execute(
+        "DELETE FROM entry_tags WHERE entry_id = ?1 AND tag = ?2",
+        params![entry_id, tag],
+    )
+    .map_err(|error| format!("failed to remove entry tag: {error}"))?;
+
+    let tags = entry_tags_db(&conn, entry_id)?;
+    emit_data_changed(&app, "entries");
+    Ok(tags)
+}
+
+#[tauri::command]
+fn list_tags_for_sprint(db: State<'_, DbState>, sprint_id: String) -> Result<Vec<String>, String> {
+    let conn = db.conn()?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT DISTINCT t.tag
+             FROM entry_tags t
+             JOIN entries e ON e.id = t.entry_id
+             WHERE e.sprint_id = ?1
+             ORDER BY t.tag",
+        )
+        .map_err(|error| format!("failed to prepare sprint tags query: {error}"))?;
+
+    let rows = stmt
+        .query_map(params![sprint_id], |row| row.get::<_, String>(0))
+        .map_err(|error| format!("failed to query sprint tags: {error}"))?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect sprint tags: {error}"))
+}
+
+#[tauri::command]
+fn list_active_dates(
+    db: State<'_, DbState>,
+    sprint_id: String,
+) -> Result<Vec<(String, usize)>, String> {
+    let conn = db.conn()?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT date, COUNT(*)
+             FROM entries
+             WHERE sprint_id = ?1
+             GROUP BY date
+             ORDER BY date",
+        )
+        .map_err(|error| format!("failed to prepare active dates query: {error}"))?;
+
+    let rows = stmt
+        .query_map(params![sprint_id], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? as usize))
+        })
+        .map_err(|error| format!("failed to query active dates: {error}"))?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect active dates: {error}"))
+}
+
+fn template_from_row(row: &Row<'_>) -> rusqlite::Result<EntryTemplate> {
+    Ok(EntryTemplate {
+        id: row.get(0)?,
+        category_id: row.get(1)?,
+        title: row.get(2)?,
+        details: row.get(3)?,
+        created_at: row.get(4)?,
+    })
+}
+
+#[tauri::command]
+fn list_templates(db: State<'_, DbState>) -> Result<Vec<EntryTemplate>, String> {
+    let conn = db.conn()?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, category_id, title, details, created_at
+             FROM templates
+             ORDER BY created_at",
+        )
+        .map_err(|error| format!("failed to prepare templates query: {error}"))?;
+
+    let rows = stmt
+        .query_map([], template_from_row)
+        .map_err(|error| format!("failed to query templates: {error}"))?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect templates: {error}"))
+}
+
+#[tauri::command]
+fn create_template(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: NewTemplateInput,
+) -> Result<EntryTemplate, String> {
+    let title = input.title.trim();
+    if title.is_empty() {
+        return Err("title is required".to_string());
+    }
+
+    let category_id = input.category_id.trim();
+    if category_id.is_empty() {
+        return Err("category_id is required".to_string());
+    }
+
+    let conn = db.conn()?;
+
+    if !category_exists(&conn, category_id)? {
+        return Err("the selected category does not exist".to_string());
+    }
+
+    let template = EntryTemplate {
+        id: next_id("template"),
+        category_id: category_id.to_string(),
+        title: title.to_string(),
+        details: normalize_details(input.details),
+        created_at: now(),
+    };
+
+    conn.execute(
+        "INSERT INTO templates (id, category_id, title, details, created_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![
+            template.id,
+            template.category_id,
+            template.title,
+            template.details,
+            template.created_at
+        ],
+    )
+    .map_err(|error| format!("failed to create template: {error}"))?;
+
+    emit_data_changed(&app, "templates");
+    Ok(template)
+}
+
+#[tauri::command]
+fn delete_template(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: DeleteTemplateInput,
+) -> Result<(), String> {
+    let template_id = input.id.trim();
+    if template_id.is_empty() {
+        return Err("template id is required".to_string());
+    }
+
+    let conn = db.conn()?;
+    let affected = conn
+        .execute("DELETE FROM templates WHERE id = ?1", params![template_id])
+        .map_err(|error| format!("failed to delete template: {error}"))?;
+
+    if affected == 0 {
+        return Err("template not found".to_string());
+    }
+
+    emit_data_changed(&app, "templates");
+    Ok(())
+}
+
+#[tauri::command]
+fn list_report_templates(db: State<'_, DbState>) -> Result<Vec<ReportTemplate>, String> {
+    let conn = db.conn()?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT name, header, created_at
+             FROM report_templates
+             ORDER BY created_at",
+        )
+        .map_err(|error| format!("failed to prepare report templates query: {error}"))?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(ReportTemplate {
+                name: row.get(0)?,
+                header: row.get(1)?,
+                created_at: row.get(2)?,
+            })
+        })
+        .map_err(|error| format!("failed to query report templates: {error}"))?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect report templates: {error}"))
+}
+
+#[tauri::command]
+fn save_report_template(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: SaveReportTemplateInput,
+) -> Result<ReportTemplate, String> {
+    let name = input.name.trim();
+    if name.is_empty() {
+        return Err("template name is required".to_string());
+    }
+
+    if input.header.trim().is_empty() {
+        return Err("template header is required".to_string());
+    }
+
+    let conn = db.conn()?;
+    conn.execute(
+        "INSERT INTO report_templates (name, header, created_at) VALUES (?1, ?2, ?3)
+         ON CONFLICT(name) DO UPDATE SET header = excluded.header",
+        params![name, input.header, now()],
+    )
+    .map_err(|error| format!("failed to save report template: {error}"))?;
+
+    let template = conn
+        .query_row(
+            "SELECT name, header, created_at FROM report_templates WHERE name = ?1",
+            params![name],
+            |row| {
+                Ok(ReportTemplate {
+                    name: row.get(0)?,
+                    header: row.get(1)?,
+                    created_at: row.get(2)?,
+                })
+            },
+        )
+        .map_err(|error| format!("failed to read saved report template: {error}"))?;
+
+    emit_data_changed(&app, "templates");
+    Ok(template)
+}
+
+#[tauri::command]
+fn delete_report_template(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: DeleteReportTemplateInput,
+) -> Result<(), String> {
+    let name = input.name.trim();
+    if name.is_empty() {
+        return Err("template name is required".to_string());
+    }
+
+    if name.eq_ignore_ascii_case(DEFAULT_REPORT_TEMPLATE_NAME) {
+        return Err("cannot delete the default report template".to_string());
+    }
+
+    let conn = db.conn()?;
+    let affected = conn
+        .execute(
+            "DELETE FROM report_templates WHERE name = ?1",
+            params![name],
+        )
+        .map_err(|error| format!("failed to delete report template: {error}"))?;
+
+    if affected == 0 {
+        return Err("report template not found".to_string());
+    }
+
+    emit_data_changed(&app, "templates");
+    Ok(())
+}
+
+#[tauri::command]
+fn apply_template(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: ApplyTemplateInput,
+) -> Result<DailyEntry, String> {
+    let conn = db.conn()?;
+
+    let template = conn
+        .query_row(
+            "SELECT id, category_id, title, details, created_at
+             FROM templates
+             WHERE id = ?1",
+            params![input.template_id.trim()],
+            template_from_row,
+        )
+        .optional()
+        .map_err(|error| format!("failed to read template: {error}"))?
+        .ok_or_else(|| "template not found".to_string())?;
+
+    if !category_exists(&conn, template.category_id.as_str())? {
+        return Err("the template's category no longer exists".to_string());
+    }
+
+    let entry = add_entry_db(
+        &conn,
+        NewDailyEntryInput {
+            sprint_id: input.sprint_id,
+            date: input.date,
+            category_id: template.category_id,
+            title: template.title,
+            details: template.details,
+            link: None,
+            reject_duplicates: None,
+        },
+    )?;
+
+    emit_data_changed(&app, "entries");
+    Ok(entry)
+}
+
+#[tauri::command]
+fn search_entries(
+    db: State<'_, DbState>,
+    input: SearchEntriesInput,
+) -> Result<Vec<DailyEntry>, String> {
+    let query = input.query.trim();
+    if query.is_empty() {
+        return Err("query is required".to_string());
+    }
+
+    let sprint_id = input
+        .sprint_id
+        .as_ref()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty());
+    let limit = input.limit.unwrap_or(100) as i64;
+    let pattern = format!("%{}%", escape_like(query));
+
+    let conn = db.conn()?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, sprint_id, date, category_id, title, details, created_at, updated_at, link, seq
+             FROM entries
+             WHERE (title LIKE ?1 ESCAPE '\\' OR details LIKE ?1 ESCAPE '\\')
+               AND (?2 IS NULL OR sprint_id = ?2)
+             ORDER BY date DESC, created_at DESC
+             LIMIT ?3",
+        )
+        .map_err(|error| format!("failed to prepare search query: {error}"))?;
+
+    let rows = stmt
+        .query_map(params![pattern, sprint_id, limit], entry_from_row)
+        .map_err(|error| format!("failed to search entries: {error}"))?;
+
+    let mut items = rows
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect search results: {error}"))?;
+
+    attach_entry_tags_db(&conn, sprint_id.as_deref(), &mut items)?;
+
+    Ok(items)
+}
+
+#[tauri::command]
+fn recent_entries(
+    db: State<'_, DbState>,
+    input: RecentEntriesInput,
+) -> Result<Vec<RecentEntry>, String> {
+    let limit = input.limit.unwrap_or(20) as i64;
+
+    let conn = db.conn()?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT e.id, e.sprint_id, e.date, e.category_id, e.title, e.details, e.created_at,
+                    e.updated_at, e.link, e.seq, s.code, s.name, COALESCE(c.name, e.category_id)
+             FROM entries e
+             JOIN sprints s ON s.id = e.sprint_id
+             LEFT JOIN categories c ON c.id = e.category_id
+             ORDER BY e.created_at DESC
+             LIMIT ?1",
+        )
+        .map_err(|error| format!("failed to prepare recent entries query: {error}"))?;
+
+    let rows = stmt
+        .query_map(params![limit], |row| {
+            Ok((
+                entry_from_row(row)?,
+                (
+                    row.get::<_, String>(10)?,
+                    row.get::<_, String>(11)?,
+                    row.get::<_, String>(12)?,
+                ),
+            ))
+        })
+        .map_err(|error| format!("failed to query recent entries: {error}"))?;
+
+    let (mut entries, labels): (Vec<_>, Vec<_>) = rows
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| format!("failed to collect recent entries: {error}"))?
+        .into_iter()
+        .unzip();
+
+    attach_entry_tags_db(&conn, None, &mut entries)?;
+
+    Ok(entries
+        .into_iter()
+        .zip(labels)
+        .map(
+            |(entry, (sprint_code, sprint_name, category_name))| RecentEntry {
+                entry,
+                sprint_code,
+                sprint_name,
+                category_name,
+            },
+        )
+        .collect())
+}
+
+fn report_date_range(
+    from_date: Option<&str>,
+    to_date: Option<&str>,
+) -> Result<(Option<String>, Option<String>), String> {
+    let parse = |raw: Option<&str>, field: &str| {
+        raw.map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(|value| {
+                NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                    .map_err(|_| format!("{field} must be in YYYY-MM-DD format"))
+            })
+            .transpose()
+    };
+
+    let from = parse(from_date, "from_date")?;
+    let to = parse(to_date, "to_date")?;
+    if let (Some(from), Some(to)) = (from, to) {
+        if to < from {
+            return Err("to_date must be on or after from_date".to_string());
+        }
+    }
+
+    let format = |date: NaiveDate| date.format("%Y-%m-%d").to_string();
+    Ok((from.map(format), to.map(format)))
+}
+
+fn parse_report_format(raw: Option<&str>) -> Result<ReportFormat, String> {
+    let value = raw.map(str::trim).unwrap_or_default().to_ascii_lowercase();
+
+    match value.as_str() {
+        "" | "markdown" | "md" => Ok(ReportFormat::Markdown),
+        "csv" => Ok(ReportFormat::Csv),
+        "html" => Ok(ReportFormat::Html),
+        "json" => Ok(ReportFormat::Json),
+        "pdf" => Ok(ReportFormat::Pdf),
+        _ => Err("format must be markdown, csv, html, json, or pdf".to_string()),
+    }
+}
+
+fn parse_report_grouping(raw: Option<&str>) -> Result<ReportGrouping, String> {
+    let value = raw.map(str::trim).unwrap_or_default().to_ascii_lowercase();
+
+    match value.as_str() {
+        "" | "day" => Ok(ReportGrouping::Day),
+        "week" => Ok(ReportGrouping::Week),
+        _ => Err("group_by must be day or week".to_string()),
+    }
+}
+
+fn report_group_key(date: &str, grouping: ReportGrouping) -> String {
+    match grouping {
+        ReportGrouping::Day => date.to_string(),
+        ReportGrouping::Week => match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+            Ok(parsed) => {
+                let week = parsed.iso_week();
+                format!("{:04}-W{:02}", week.year(), week.week())
+            }
+            Err(_) => date.to_string(),
+        },
+    }
+}
+
+fn group_entries(
+    entries: &[DailyEntry],
+    category_name_map: &HashMap<String, String>,
+    grouping: ReportGrouping,
+) -> BTreeMap<String, BTreeMap<String, Vec<DailyEntry>>> {
+    let mut grouped: BTreeMap<String, BTreeMap<String, Vec<DailyEntry>>> = BTreeMap::new();
+
+    for entry in entries {
+        let category_label = category_name_map
+            .get(&entry.category_id)
+            .cloned()
+            .unwrap_or_else(|| entry.category_id.clone());
+
+        grouped
+            .entry(report_group_key(&entry.date, grouping))
+            .or_default()
+            .entry(category_label)
+            .or_default()
+            .push(entry.clone());
+    }
+
+    grouped
+}
+
+fn markdown_heading_anchor(heading: &str) -> String {
+    heading
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|ch| {
+            if ch.is_alphanumeric() || ch == '-' || ch == '_' {
+                Some(ch)
+            } else if ch.is_whitespace() {
+                Some('-')
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn report_group_heading(group: &str, grouping: ReportGrouping) -> String {
+    match grouping {
+        ReportGrouping::Day => group.to_string(),
+        ReportGrouping::Week => format!("Week {group}"),
+    }
+}
+
+fn report_template_header_db(conn: &Connection, name: Option<&str>) -> Result<String, String> {
+    let name = name
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .unwrap_or(DEFAULT_REPORT_TEMPLATE_NAME);
+
+    let header = conn
+        .query_row(
+            "SELECT header FROM report_templates WHERE name = ?1",
+            params![name],
+            |row| row.get::<_, String>(0),
+        )
+        .optional()
+        .map_err(|error| format!("failed to read report template: {error}"))?;
+
+    match header {
+        Some(header) => Ok(header),
+        None if name.eq_ignore_ascii_case(DEFAULT_REPORT_TEMPLATE_NAME) => {
+            Ok(DEFAULT_REPORT_HEADER.to_string())
+        }
+        None => Err(format!("report template not found: {name}")),
+    }
+}
+
+fn render_report_header(template: &str, sprint: &Sprint, total: usize) -> String {
+    let placeholder = |key: &str| -> Option<String> {
+        match key {
+            "sprint_name" => Some(sprint.name.clone()),
+            "sprint_code" => Some(sprint.code.clone()),
+            "start_date" => Some(sprint.start_date.clone()),
+            "end_date" => Some(
+                sprint
+                    .end_date
+                    .clone()
+                    .unwrap_or_else(|| "open".to_string()),
+            ),
+            "total" => Some(total.to_string()),
+            "description" => Some(
+                sprint
+                    .description
+                    .as_ref()
+                    .map(|description| format!("{description}\n\n"))
+                    .unwrap_or_default(),
+            ),
+            "exported_at" => Some(now()),
+            _ => None,
+        }
+    };
+
+    let mut header = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        header.push_str(&rest[..start]);
+        let candidate = &rest[start..];
+        let replacement = candidate
+            .find('}')
+            .and_then(|end| placeholder(&candidate[1..end]).map(|value| (end, value)));
+
+        match replacement {
+            Some((end, value)) => {
+                header.push_str(&value);
+                rest = &candidate[end + 1..];
+            }
+            None => {
+                header.push('{');
+                rest = &candidate[1..];
+            }
+        }
+    }
+    header.push_str(rest);
+
+    header
+}
+
+fn push_markdown_groups(
+    markdown: &mut String,
+    grouped: BTreeMap<String, BTreeMap<String, Vec<DailyEntry>>>,
+    grouping: ReportGrouping,
+    include_ids: bool,
+    level: usize,
+) {
+    let group_marker = "#".repeat(level);
+    let category_marker = "#".repeat(level + 1);
+
+    for (group, by_category) in grouped {
+        markdown.push_str(&format!(
+            "{group_marker} {}\n\n",
+            report_group_heading(&group, grouping)
+        ));
+        if matches!(grouping, ReportGrouping::Week) {
+            let total: usize = by_category.values().map(Vec::len).sum();
+            markdown.push_str(&format!("- Items: {}\n\n", total));
+        }
+        for (category_label, entries) in by_category {
+            markdown.push_str(&format!("{category_marker} {}\n", category_label));
+            for item in entries {
+                markdown.push_str("- ");
+                if include_ids {
+                    markdown.push_str(&format!("[{}] ", item.id));
+                }
+                markdown.push_str(&escape_markdown(&item.title));
+                let details = item.details.as_deref();
+                if let Some(details) = details.filter(|details| !details.contains('\n')) {
+                    markdown.push_str(&format!(" - {}", escape_markdown(details)));
+                }
+                if let Some(link) = &item.link {
+                    markdown.push_str(&format!(" ([link]({}))", link));
+                }
+                markdown.push('\n');
+                if let Some(details) = details.filter(|details| details.contains('\n')) {
+                    markdown.push_str(&markdown_details_block(details));
+                }
+            }
+            markdown.push('\n');
+        }
+    }
+}
+
+fn render_markdown_report(
+    sprint: &Sprint,
+    entries: &[DailyEntry],
+    category_name_map: &HashMap<String, String>,
+    grouping: ReportGrouping,
+    include_toc: bool,
+    include_ids: bool,
+    header: &str,
+) -> String {
+    let grouped = group_entries(entries, category_name_map, grouping);
+
+    let mut markdown = render_report_header(header, sprint, entries.len());
+
+    if grouped.is_empty() {
+        markdown.push_str("No items found for the selected filters.\n");
+    } else {
+        if include_toc {
+            markdown.push_str("## Contents\n\n");
+            for group in grouped.keys() {
+                let heading = report_group_heading(group, grouping);
+                markdown.push_str(&format!(
+                    "- [{}](#{})\n",
+                    heading,
+                    markdown_heading_anchor(&heading)
+                ));
+            }
+            markdown.push('\n');
+        }
+
+        push_markdown_groups(&mut markdown, grouped, grouping, include_ids, 2);
+    }
+
+    markdown
+}
 
-    let digits = rest
-        .trim()
-        .trim_start_matches('-')
-        .trim_start_matches('_')
-        .trim();
+fn render_markdown_summary(
+    entries: &[DailyEntry],
+    category_name_map: &HashMap<String, String>,
+) -> String {
+    if entries.is_empty() {
+        return String::new();
+    }
 
-    digits.parse::<u32>().ok()
-}
+    let active_days = entries
+        .iter()
+        .map(|entry| entry.date.as_str())
+        .collect::<HashSet<_>>()
+        .len();
 
-fn format_sprint_code(number: u32) -> String {
-    format!("sprint-{number}")
-}
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for entry in entries {
+        let category_label = category_name_map
+            .get(&entry.category_id)
+            .cloned()
+            .unwrap_or_else(|| entry.category_id.clone());
+        *counts.entry(category_label).or_default() += 1;
+    }
 
-fn default_categories() -> Vec<Category> {
-    let created_at = now();
+    let mut by_category = counts.into_iter().collect::<Vec<_>>();
+    by_category.sort_by(|left, right| right.1.cmp(&left.1).then(left.0.cmp(&right.0)));
 
-    vec![
-        Category {
-            id: "pr-reviews".to_string(),
-            name: "PR-Reviews".to_string(),
-            created_at: created_at.clone(),
-        },
-        Category {
-            id: "meeting".to_string(),
-            name: "Meeting".to_string(),
-            created_at: created_at.clone(),
-        },
-        Category {
-            id: "tasks".to_string(),
-            name: "Tasks".to_string(),
-            created_at,
-        },
-    ]
+    let total = entries.len();
+    let mut markdown = String::new();
+    markdown.push_str("## Summary\n\n");
+    markdown.push_str(&format!("- Total Items: {}\n", total));
+    markdown.push_str(&format!("- Active Days: {}\n", active_days));
+    for (category_label, count) in by_category {
+        let percent = count as f64 * 100.0 / total as f64;
+        markdown.push_str(&format!(
+            "- {}: {} ({:.0}%)\n",
+            category_label, count, percent
+        ));
+    }
+    markdown.push('\n');
+
+    markdown
 }
 
-fn ensure_default_categories(data: &mut AppData) {
-    if data.categories.is_empty() {
-        data.categories = default_categories();
+fn csv_field(raw: &str) -> String {
+    if raw.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", raw.replace('"', "\"\""))
+    } else {
+        raw.to_string()
     }
 }
 
-fn assign_missing_sprint_codes(data: &mut AppData) -> bool {
-    let mut changed = false;
-    let mut highest = data
-        .sprints
-        .iter()
-        .filter_map(|sprint| sprint_number(&sprint.code).or_else(|| sprint_number(&sprint.name)))
-        .max()
-        .unwrap_or(0);
+fn render_csv_report(
+    entries: &[DailyEntry],
+    category_name_map: &HashMap<String, String>,
+) -> String {
+    let mut csv = String::from("date,category,title,details\n");
 
-    let mut index_list = (0..data.sprints.len()).collect::<Vec<_>>();
-    index_list.sort_by(|left, right| {
-        data.sprints[*left]
-            .created_at
-            .cmp(&data.sprints[*right].created_at)
-    });
+    for entry in entries {
+        let category_label = category_name_map
+            .get(&entry.category_id)
+            .map(String::as_str)
+            .unwrap_or(entry.category_id.as_str());
+
+        csv.push_str(&format!(
+            "{},{},{},{}\n",
+            csv_field(&entry.date),
+            csv_field(category_label),
+            csv_field(&entry.title),
+            csv_field(entry.details.as_deref().unwrap_or_default())
+        ));
+    }
 
-    for index in &index_list {
-        let sprint = &mut data.sprints[*index];
+    csv
+}
 
-        if sprint.code.trim().is_empty() {
-            if let Some(number) = sprint_number(&sprint.name) {
-                sprint.code = format_sprint_code(number);
-                if number > highest {
-                    highest = number;
+fn escape_html(raw: &str) -> String {
+    raw.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn render_html_report(
+    sprint: &Sprint,
+    entries: &[DailyEntry],
+    category_name_map: &HashMap<String, String>,
+    grouping: ReportGrouping,
+) -> String {
+    let grouped = group_entries(entries, category_name_map, grouping);
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str(&format!(
+        "<title>Sprint Report: {}</title>\n",
+        escape_html(&sprint.name)
+    ));
+    html.push_str(
+        "<style>\n\
+         body { font-family: -apple-system, BlinkMacSystemFont, \"Segoe UI\", sans-serif; max-width: 760px; margin: 2rem auto; padding: 0 1rem; color: #1f2933; line-height: 1.5; }\n\
+         h1 { font-size: 1.6rem; margin-bottom: 0.5rem; }\n\
+         h2 { font-size: 1.2rem; margin-top: 2rem; border-bottom: 1px solid #d9e2ec; padding-bottom: 0.25rem; }\n\
+         h3 { font-size: 1rem; margin-bottom: 0.25rem; color: #486581; }\n\
+         .meta { color: #627d98; font-size: 0.9rem; }\n\
+         .details { color: #52606d; }\n\
+         </style>\n",
+    );
+    html.push_str("</head>\n<body>\n");
+    html.push_str(&format!(
+        "<h1>Sprint Report: {}</h1>\n",
+        escape_html(&sprint.name)
+    ));
+    html.push_str("<ul class=\"meta\">\n");
+    html.push_str(&format!(
+        "<li>Sprint Code: {}</li>\n",
+        escape_html(&sprint.code)
+    ));
+    html.push_str(&format!(
+        "<li>Sprint Window: {} to {}</li>\n",
+        escape_html(&sprint.start_date),
+        escape_html(sprint.end_date.as_deref().unwrap_or("open"))
+    ));
+    html.push_str(&format!("<li>Exported At: {}</li>\n", now()));
+    html.push_str("</ul>\n");
+
+    if grouped.is_empty() {
+        html.push_str("<p>No items found for the selected filters.</p>\n");
+    } else {
+        for (group, by_category) in grouped {
+            match grouping {
+                ReportGrouping::Day => {
+                    html.push_str(&format!("<h2>{}</h2>\n", escape_html(&group)))
+                }
+                ReportGrouping::Week => {
+                    let total: usize = by_category.values().map(Vec::len).sum();
+                    html.push_str(&format!("<h2>Week {}</h2>\n", escape_html(&group)));
+                    html.push_str(&format!("<p class=\"meta\">Items: {}</p>\n", total));
                 }
-                changed = true;
-            }
-        } else if let Some(number) = sprint_number(&sprint.code) {
-            let normalized = format_sprint_code(number);
-            if sprint.code != normalized {
-                sprint.code = normalized;
-                changed = true;
-            }
-            if number > highest {
-                highest = number;
             }
-        }
-    }
 
-    for index in &index_list {
-        let sprint = &mut data.sprints[*index];
-        if sprint.code.trim().is_empty() {
-            highest += 1;
-            sprint.code = format_sprint_code(highest);
-            changed = true;
+            for (category_label, entries) in by_category {
+                html.push_str(&format!(
+                    "<h3>{}</h3>\n<ul>\n",
+                    escape_html(&category_label)
+                ));
+                for item in entries {
+                    html.push_str(&format!("<li>{}", escape_html(&item.title)));
+                    if let Some(details) = item.details {
+                        html.push_str(&format!(
+                            " <span class=\"details\">- {}</span>",
+                            escape_html(&details)
+                        ));
+                    }
+                    html.push_str("</li>\n");
+                }
+                html.push_str("</ul>\n");
+            }
         }
     }
 
-    changed
+    html.push_str("</body>\n</html>\n");
+    html
 }
 
-fn init_schema(conn: &Connection) -> Result<(), String> {
-    conn.execute_batch(
-        "
-        PRAGMA foreign_keys = ON;
+fn render_json_report(
+    sprint: &Sprint,
+    entries: &[DailyEntry],
+    category_name_map: &HashMap<String, String>,
+    grouping: ReportGrouping,
+) -> Result<String, String> {
+    let days = group_entries(entries, category_name_map, grouping)
+        .into_iter()
+        .map(|(date, by_category)| ReportJsonDay {
+            date,
+            categories: by_category
+                .into_iter()
+                .map(|(name, items)| ReportJsonCategory {
+                    name,
+                    items: items
+                        .into_iter()
+                        .map(|item| ReportJsonItem {
+                            title: item.title,
+                            details: item.details,
+                        })
+                        .collect(),
+                })
+                .collect(),
+        })
+        .collect();
 
-        CREATE TABLE IF NOT EXISTS categories (
-            id TEXT PRIMARY KEY,
-            name TEXT NOT NULL UNIQUE COLLATE NOCASE,
-            created_at TEXT NOT NULL
-        );
+    let report = ReportJson {
+        sprint: sprint.clone(),
+        exported_at: now(),
+        total_items: entries.len(),
+        days,
+    };
 
-        CREATE TABLE IF NOT EXISTS sprints (
-            id TEXT PRIMARY KEY,
-            code TEXT NOT NULL UNIQUE,
-            name TEXT NOT NULL,
-            start_date TEXT NOT NULL,
-            end_date TEXT,
-            created_at TEXT NOT NULL
-        );
+    serde_json::to_string_pretty(&report)
+        .map_err(|error| format!("failed to serialize report: {error}"))
+}
 
-        CREATE TABLE IF NOT EXISTS entries (
-            id TEXT PRIMARY KEY,
-            sprint_id TEXT NOT NULL,
-            date TEXT NOT NULL,
-            category_id TEXT NOT NULL,
-            title TEXT NOT NULL,
-            details TEXT,
-            created_at TEXT NOT NULL,
-            FOREIGN KEY (sprint_id) REFERENCES sprints(id) ON DELETE CASCADE,
-            FOREIGN KEY (category_id) REFERENCES categories(id) ON DELETE RESTRICT
-        );
+#[tauri::command]
+fn get_sprint_stats(db: State<'_, DbState>, sprint_id: String) -> Result<SprintStats, String> {
+    let conn = db.conn()?;
 
-        CREATE INDEX IF NOT EXISTS idx_entries_sprint_date
-            ON entries (sprint_id, date, category_id, created_at);
-        ",
-    )
-    .map_err(|error| format!("failed to initialize database schema: {error}"))
-}
+    if !sprint_exists(&conn, sprint_id.as_str())? {
+        return Err("the selected sprint does not exist".to_string());
+    }
 
-fn db_is_empty(conn: &Connection) -> Result<bool, String> {
-    let categories_count: i64 = conn
-        .query_row("SELECT COUNT(*) FROM categories", [], |row| row.get(0))
-        .map_err(|error| format!("failed to count categories: {error}"))?;
-    let sprints_count: i64 = conn
-        .query_row("SELECT COUNT(*) FROM sprints", [], |row| row.get(0))
-        .map_err(|error| format!("failed to count sprints: {error}"))?;
-    let entries_count: i64 = conn
-        .query_row("SELECT COUNT(*) FROM entries", [], |row| row.get(0))
-        .map_err(|error| format!("failed to count entries: {error}"))?;
+    let category_name_map: HashMap<String, String> = list_categories_db(&conn)?
+        .into_iter()
+        .map(|category| (category.id, category.name))
+        .collect();
 
-    Ok(categories_count == 0 && sprints_count == 0 && entries_count == 0)
-}
+    let mut entries_by_category = count_entries_grouped_db(&conn, &sprint_id, "category_id")?
+        .into_iter()
+        .map(|(category_id, count)| {
+            let label = category_name_map
+                .get(&category_id)
+                .cloned()
+                .unwrap_or(category_id);
+            (label, count)
+        })
+        .collect::<Vec<_>>();
+    entries_by_category.sort_by(|left, right| right.1.cmp(&left.1).then(left.0.cmp(&right.0)));
 
-fn migrate_legacy_json_if_needed(app: &AppHandle, conn: &mut Connection) -> Result<(), String> {
-    if !db_is_empty(conn)? {
-        return Ok(());
-    }
+    let entries_by_date = count_entries_grouped_db(&conn, &sprint_id, "date")?;
+    let total_entries = entries_by_date.iter().map(|(_, count)| count).sum();
 
-    let legacy_path = legacy_data_file_path(app)?;
-    if !legacy_path.exists() {
-        return Ok(());
-    }
+    Ok(SprintStats {
+        total_entries,
+        active_days: entries_by_date.len(),
+        entries_by_category,
+        entries_by_date,
+    })
+}
 
-    let raw = fs::read_to_string(&legacy_path).map_err(|error| {
-        format!(
-            "unable to read legacy data file {}: {error}",
-            legacy_path.display()
-        )
-    })?;
+#[tauri::command]
+fn get_burndown(db: State<'_, DbState>, sprint_id: String) -> Result<Vec<BurndownPoint>, String> {
+    let conn = db.conn()?;
 
-    let mut legacy: AppData = serde_json::from_str(&raw).map_err(|error| {
-        format!(
-            "invalid legacy data format in {}: {error}",
-            legacy_path.display()
-        )
-    })?;
+    let sprint = get_sprint_db(&conn, sprint_id.as_str())?
+        .ok_or_else(|| "the selected sprint does not exist".to_string())?;
 
-    ensure_default_categories(&mut legacy);
-    assign_missing_sprint_codes(&mut legacy);
+    let start = NaiveDate::parse_from_str(&sprint.start_date, "%Y-%m-%d")
+        .map_err(|_| "start_date must be in YYYY-MM-DD format".to_string())?;
 
-    let mut known_category_ids = legacy
-        .categories
-        .iter()
-        .map(|category| category.id.clone())
-        .collect::<HashSet<_>>();
+    let by_date = count_entries_grouped_db(&conn, &sprint.id, "date")?
+        .into_iter()
+        .filter_map(|(date, count)| {
+            NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+                .ok()
+                .map(|parsed| (parsed, count))
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    let end = match &sprint.end_date {
+        Some(end_date) => NaiveDate::parse_from_str(end_date, "%Y-%m-%d")
+            .map_err(|_| "end_date must be in YYYY-MM-DD format".to_string())?,
+        None => by_date
+            .keys()
+            .next_back()
+            .copied()
+            .unwrap_or(start)
+            .max(start),
+    };
 
-    for entry in &legacy.entries {
-        let category_id = entry.category_id.trim();
-        if category_id.is_empty() || known_category_ids.contains(category_id) {
-            continue;
-        }
+    burndown_points(start, end, &by_date)
+}
 
-        legacy.categories.push(Category {
-            id: category_id.to_string(),
-            name: humanize_category_id(category_id),
-            created_at: now(),
+fn burndown_points(
+    start: NaiveDate,
+    end: NaiveDate,
+    by_date: &BTreeMap<NaiveDate, usize>,
+) -> Result<Vec<BurndownPoint>, String> {
+    if (end - start).num_days() >= MAX_BURNDOWN_DAYS {
+        return Err(format!(
+            "burndown range is too long (max {MAX_BURNDOWN_DAYS} days)"
+        ));
+    }
+
+    let mut points = Vec::new();
+    let mut cumulative = 0usize;
+    let mut day = Some(start);
+    while let Some(current) = day.filter(|current| *current <= end) {
+        let entries_that_day = by_date.get(&current).copied().unwrap_or(0);
+        cumulative += entries_that_day;
+        points.push(BurndownPoint {
+            date: current.format("%Y-%m-%d").to_string(),
+            entries_that_day,
+            cumulative,
         });
-        known_category_ids.insert(category_id.to_string());
+        day = current.succ_opt();
     }
 
-    let tx = conn
-        .transaction()
-        .map_err(|error| format!("failed to start migration transaction: {error}"))?;
+    Ok(points)
+}
 
-    for category in &legacy.categories {
-        if category.id.trim().is_empty() || category.name.trim().is_empty() {
-            continue;
-        }
+#[tauri::command]
+fn get_activity_stats(db: State<'_, DbState>) -> Result<ActivityStats, String> {
+    let conn = db.conn()?;
 
-        tx.execute(
-            "INSERT OR IGNORE INTO categories (id, name, created_at) VALUES (?1, ?2, ?3)",
-            params![category.id, category.name, category.created_at],
-        )
-        .map_err(|error| format!("failed to migrate category {}: {error}", category.id))?;
-    }
+    let mut stmt = conn
+        .prepare("SELECT DISTINCT date FROM entries ORDER BY date")
+        .map_err(|error| format!("failed to prepare activity query: {error}"))?;
 
-    let mut migrated_sprint_ids = HashSet::new();
-    for sprint in &legacy.sprints {
-        if sprint.id.trim().is_empty() || sprint.start_date.trim().is_empty() {
-            continue;
+    let rows = stmt
+        .query_map([], |row| row.get::<_, String>(0))
+        .map_err(|error| format!("failed to query activity dates: {error}"))?;
+
+    let mut dates = BTreeSet::new();
+    for row in rows {
+        let raw = row.map_err(|error| format!("failed to read activity date: {error}"))?;
+        if let Ok(date) = NaiveDate::parse_from_str(raw.trim(), "%Y-%m-%d") {
+            dates.insert(date);
         }
+    }
 
-        let code = if sprint.code.trim().is_empty() {
-            format_sprint_code(Utc::now().timestamp_subsec_nanos())
-        } else {
-            sprint.code.trim().to_string()
-        };
+    let mut longest_streak = 0;
+    let mut streak = 0;
+    let mut previous: Option<NaiveDate> = None;
 
-        let name = if sprint.name.trim().is_empty() {
-            code.clone()
-        } else {
-            sprint.name.trim().to_string()
+    for date in &dates {
+        streak = match previous {
+            Some(prev) if *date - prev == Duration::days(1) => streak + 1,
+            _ => 1,
         };
+        longest_streak = longest_streak.max(streak);
+        previous = Some(*date);
+    }
 
-        tx.execute(
-            "INSERT OR IGNORE INTO sprints (id, code, name, start_date, end_date, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-            params![
-                sprint.id,
-                code,
-                name,
-                sprint.start_date,
-                sprint.end_date,
-                sprint.created_at
-            ],
-        )
-        .map_err(|error| format!("failed to migrate sprint {}: {error}", sprint.id))?;
-
-        migrated_sprint_ids.insert(sprint.id.clone());
+    let mut current_streak = 0;
+    let mut cursor = Local::now().date_naive();
+    while dates.contains(&cursor) {
+        current_streak += 1;
+        cursor -= Duration::days(1);
     }
 
-    for entry in &legacy.entries {
-        if entry.sprint_id.trim().is_empty()
-            || entry.category_id.trim().is_empty()
-            || entry.title.trim().is_empty()
-            || entry.date.trim().is_empty()
-        {
-            continue;
-        }
+    Ok(ActivityStats {
+        current_streak,
+        longest_streak,
+        total_days_logged: dates.len(),
+    })
+}
+
+#[tauri::command]
+fn get_weekday_distribution(
+    db: State<'_, DbState>,
+    sprint_id: Option<String>,
+) -> Result<[usize; 7], String> {
+    let conn = db.conn()?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT date, COUNT(*)
+             FROM entries
+             WHERE ?1 IS NULL OR sprint_id = ?1
+             GROUP BY date",
+        )
+        .map_err(|error| format!("failed to prepare weekday query: {error}"))?;
 
-        if !migrated_sprint_ids.contains(&entry.sprint_id) {
-            continue;
+    let rows = stmt
+        .query_map(params![sprint_id], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+        })
+        .map_err(|error| format!("failed to query weekday distribution: {error}"))?;
+
+    let mut distribution = [0usize; 7];
+    for row in rows {
+        let (raw, count) =
+            row.map_err(|error| format!("failed to read weekday distribution: {error}"))?;
+        if let Ok(date) = NaiveDate::parse_from_str(raw.trim(), "%Y-%m-%d") {
+            distribution[date.weekday().num_days_from_monday() as usize] += count as usize;
         }
-
-        tx.execute(
-            "INSERT OR IGNORE INTO entries (id, sprint_id, date, category_id, title, details, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
-            params![
-                if entry.id.trim().is_empty() {
-                    next_id("entry-import")
-                } else {
-                    entry.id.clone()
-                },
-                entry.sprint_id,
-                entry.date,
-                entry.category_id,
-                entry.title,
-                entry.details,
-                entry.created_at
-            ],
-        )
-        .map_err(|error| format!("failed to migrate entry {}: {error}", entry.id))?;
     }
 
-    tx.commit()
-        .map_err(|error| format!("failed to commit legacy migration: {error}"))?;
-
-    Ok(())
+    Ok(distribution)
 }
 
-fn ensure_default_categories_db(conn: &Connection) -> Result<(), String> {
-    let count: i64 = conn
-        .query_row("SELECT COUNT(*) FROM categories", [], |row| row.get(0))
-        .map_err(|error| format!("failed to count categories: {error}"))?;
-
-    if count > 0 {
-        return Ok(());
-    }
+#[tauri::command]
+fn get_velocity_trend(
+    db: State<'_, DbState>,
+    input: VelocityTrendInput,
+) -> Result<Vec<SprintVelocity>, String> {
+    let last_n = input.last_n.unwrap_or(DEFAULT_VELOCITY_SPRINTS);
+    let conn = db.conn()?;
 
-    for category in default_categories() {
-        conn.execute(
-            "INSERT INTO categories (id, name, created_at) VALUES (?1, ?2, ?3)",
-            params![category.id, category.name, category.created_at],
+    let mut stmt = conn
+        .prepare(
+            "SELECT s.code, s.name, s.start_date, s.end_date, COUNT(e.id), COUNT(DISTINCT e.date)
+             FROM sprints s
+             LEFT JOIN entries e ON e.sprint_id = s.id
+             GROUP BY s.id
+             ORDER BY s.start_date DESC, s.created_at DESC
+             LIMIT ?1",
         )
-        .map_err(|error| format!("failed to seed default category {}: {error}", category.id))?;
+        .map_err(|error| format!("failed to prepare velocity query: {error}"))?;
+
+    let rows = stmt
+        .query_map(params![last_n as i64], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, Option<String>>(3)?,
+                row.get::<_, i64>(4)?,
+                row.get::<_, i64>(5)?,
+            ))
+        })
+        .map_err(|error| format!("failed to query velocity: {error}"))?;
+
+    let mut trend = Vec::new();
+    for row in rows {
+        let (code, name, start_date, end_date, total, active_days) =
+            row.map_err(|error| format!("failed to read velocity row: {error}"))?;
+
+        let window_days = end_date.as_deref().and_then(|end| {
+            let start = NaiveDate::parse_from_str(&start_date, "%Y-%m-%d").ok()?;
+            let end = NaiveDate::parse_from_str(end, "%Y-%m-%d").ok()?;
+            Some((end - start).num_days() + 1)
+        });
+        let days = window_days.filter(|days| *days > 0).unwrap_or(active_days);
+
+        trend.push(SprintVelocity {
+            sprint_code: code,
+            sprint_name: name,
+            total_entries: total as usize,
+            entries_per_day: if days > 0 {
+                total as f64 / days as f64
+            } else {
+                0.0
+            },
+        });
     }
 
-    Ok(())
+    trend.reverse();
+    Ok(trend)
 }
 
-fn migrate_preview_category_db(conn: &Connection) -> Result<(), String> {
-    let preview_created_at = conn
-        .query_row(
-            "SELECT created_at FROM categories WHERE id = 'preview' LIMIT 1",
-            [],
-            |row| row.get::<_, String>(0),
+fn write_pdf_report(html: &str, pdf_path: &Path) -> Result<(), String> {
+    let html_path = pdf_path.with_extension("html");
+    fs::write(&html_path, html).map_err(|error| {
+        format!(
+            "unable to write intermediate html {}: {error}",
+            html_path.display()
         )
-        .optional()
-        .map_err(|error| format!("failed to query legacy preview category: {error}"))?;
+    })?;
 
-    let Some(preview_created_at) = preview_created_at else {
-        return Ok(());
-    };
+    let result = convert_html_to_pdf(&html_path, pdf_path);
+    let _ = fs::remove_file(&html_path);
+    result
+}
 
-    let target_id = conn
-        .query_row(
-            "SELECT id FROM categories WHERE id = 'pr-reviews' LIMIT 1",
-            [],
-            |row| row.get::<_, String>(0),
-        )
-        .optional()
-        .map_err(|error| format!("failed to check PR-Reviews category id: {error}"))?
-        .or(conn
-            .query_row(
-                "SELECT id FROM categories WHERE lower(name) = lower('PR-Reviews') LIMIT 1",
-                [],
-                |row| row.get::<_, String>(0),
-            )
-            .optional()
-            .map_err(|error| format!("failed to check PR-Reviews category name: {error}"))?);
+fn convert_html_to_pdf(html_path: &Path, pdf_path: &Path) -> Result<(), String> {
+    let html = html_path.to_string_lossy().to_string();
+    let pdf = pdf_path.to_string_lossy().to_string();
 
-    let target_id = if let Some(existing_id) = target_id {
-        existing_id
-    } else {
-        conn.execute(
-            "INSERT INTO categories (id, name, created_at) VALUES (?1, ?2, ?3)",
-            params!["pr-reviews", "PR-Reviews", preview_created_at],
-        )
-        .map_err(|error| format!("failed to create PR-Reviews category: {error}"))?;
-        "pr-reviews".to_string()
+    let chrome_args = vec![
+        "--headless".to_string(),
+        "--disable-gpu".to_string(),
+        "--no-pdf-header-footer".to_string(),
+        format!("--print-to-pdf={pdf}"),
+        html.clone(),
+    ];
+
+    let chrome_programs: Vec<&str> = {
+        #[cfg(target_os = "macos")]
+        {
+            vec![
+                "/Applications/Google Chrome.app/Contents/MacOS/Google Chrome",
+                "/Applications/Chromium.app/Contents/MacOS/Chromium",
+            ]
+        }
+        #[cfg(target_os = "windows")]
+        {
+            vec!["chrome", "msedge"]
+        }
+        #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+        {
+            vec![
+                "chromium",
+                "chromium-browser",
+                "google-chrome",
+                "google-chrome-stable",
+            ]
+        }
     };
 
-    conn.execute(
-        "UPDATE entries SET category_id = ?1 WHERE category_id = 'preview'",
-        params![target_id],
-    )
-    .map_err(|error| format!("failed to move preview entries to PR-Reviews: {error}"))?;
+    let mut attempts = vec![(
+        "wkhtmltopdf",
+        vec!["--quiet".to_string(), html.clone(), pdf.clone()],
+    )];
+    for program in chrome_programs {
+        attempts.push((program, chrome_args.clone()));
+    }
 
-    conn.execute("DELETE FROM categories WHERE id = 'preview'", [])
-        .map_err(|error| format!("failed to remove legacy preview category: {error}"))?;
+    for (program, args) in attempts {
+        let status = Command::new(program)
+            .args(&args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status();
 
-    conn.execute(
-        "UPDATE categories SET name = 'PR-Reviews' WHERE id = 'pr-reviews'",
-        [],
+        if matches!(status, Ok(status) if status.success()) && pdf_path.is_file() {
+            return Ok(());
+        }
+    }
+
+    Err(
+        "no PDF converter available (expected wkhtmltopdf, or Chrome/Chromium for headless printing)"
+            .to_string(),
     )
-    .map_err(|error| format!("failed to normalize PR-Reviews category name: {error}"))?;
+}
 
-    Ok(())
+fn sort_report_entries(entries: &mut [DailyEntry]) {
+    entries.sort_by(|left, right| {
+        left.date
+            .cmp(&right.date)
+            .then(left.category_id.cmp(&right.category_id))
+    });
 }
 
-fn ensure_sprint_codes_db(conn: &Connection) -> Result<(), String> {
-    let mut stmt = conn
-        .prepare("SELECT id, code, name, created_at FROM sprints ORDER BY created_at")
-        .map_err(|error| format!("failed to load sprints for code normalization: {error}"))?;
+#[tauri::command]
+fn generate_report(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: ReportInput,
+) -> Result<ReportOutput, String> {
+    let format = parse_report_format(input.format.as_deref())?;
+    let grouping = parse_report_grouping(input.group_by.as_deref())?;
+
+    if matches!(format, ReportFormat::Pdf) && !input.write_file.unwrap_or(true) {
+        return Err("pdf reports require write_file".to_string());
+    }
 
-    let mapped = stmt
-        .query_map([], |row| {
-            Ok((
-                row.get::<_, String>(0)?,
-                row.get::<_, String>(1)?,
-                row.get::<_, String>(2)?,
-                row.get::<_, String>(3)?,
-            ))
-        })
-        .map_err(|error| format!("failed to read sprint rows: {error}"))?;
+    let (from_date, to_date) =
+        report_date_range(input.from_date.as_deref(), input.to_date.as_deref())?;
 
-    let mut rows = mapped
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|error| format!("failed to collect sprint rows: {error}"))?;
+    let conn = db.conn()?;
 
-    rows.sort_by(|left, right| left.3.cmp(&right.3));
+    let sprint = get_sprint_db(&conn, input.sprint_id.as_str())?
+        .ok_or_else(|| "the selected sprint does not exist".to_string())?;
 
-    let mut used_numbers = HashSet::<u32>::new();
-    let mut highest = 0u32;
-    let mut updates = Vec::<(String, String)>::new();
+    let category_filter = input.categories.and_then(|categories| {
+        if categories.is_empty() {
+            None
+        } else {
+            Some(categories)
+        }
+    });
 
-    for (id, code, name, _) in &rows {
-        let parsed = sprint_number(code).or_else(|| sprint_number(name));
+    let category_set = category_filter
+        .as_ref()
+        .map(|categories| categories.iter().cloned().collect::<BTreeSet<_>>());
 
-        let chosen = if let Some(value) = parsed {
-            if used_numbers.contains(&value) {
-                let mut candidate = highest + 1;
-                while used_numbers.contains(&candidate) {
-                    candidate += 1;
-                }
-                candidate
-            } else {
-                value
-            }
+    let categories = list_categories_db(&conn)?;
+    let category_name_map: HashMap<String, String> = categories
+        .iter()
+        .map(|category| (category.id.clone(), category.name.clone()))
+        .collect();
+
+    let mut filtered = list_entries_for_sprint_db(
+        &conn,
+        input.sprint_id.as_str(),
+        from_date.as_deref(),
+        to_date.as_deref(),
+    )?
+    .into_iter()
+    .filter(|entry| {
+        if let Some(set) = &category_set {
+            set.contains(&entry.category_id)
         } else {
-            let mut candidate = highest + 1;
-            while used_numbers.contains(&candidate) {
-                candidate += 1;
+            true
+        }
+    })
+    .collect::<Vec<_>>();
+
+    sort_report_entries(&mut filtered);
+
+    let (content, extension) = match format {
+        ReportFormat::Markdown => {
+            let mut content = render_markdown_report(
+                &sprint,
+                &filtered,
+                &category_name_map,
+                grouping,
+                input.include_toc.unwrap_or(false),
+                input.include_ids.unwrap_or(false),
+                &report_template_header_db(&conn, input.template_name.as_deref())?,
+            );
+            if input.include_summary.unwrap_or(true) {
+                content.push_str(&render_markdown_summary(&filtered, &category_name_map));
             }
-            candidate
-        };
-
-        used_numbers.insert(chosen);
-        if chosen > highest {
-            highest = chosen;
+            (content, "md")
         }
+        ReportFormat::Csv => (render_csv_report(&filtered, &category_name_map), "csv"),
+        ReportFormat::Html => (
+            render_html_report(&sprint, &filtered, &category_name_map, grouping),
+            "html",
+        ),
+        ReportFormat::Json => (
+            render_json_report(&sprint, &filtered, &category_name_map, grouping)?,
+            "json",
+        ),
+        ReportFormat::Pdf => (
+            render_html_report(&sprint, &filtered, &category_name_map, grouping),
+            "pdf",
+        ),
+    };
 
-        let normalized = format_sprint_code(chosen);
-        if code != &normalized {
-            updates.push((id.clone(), normalized));
-        }
+    if !input.write_file.unwrap_or(true) {
+        return Ok(ReportOutput {
+            markdown: content.clone(),
+            content,
+            file_path: String::new(),
+            total_items: filtered.len(),
+        });
     }
 
-    for (id, code) in updates {
-        conn.execute(
-            "UPDATE sprints SET code = ?1 WHERE id = ?2",
-            params![code, id],
-        )
-        .map_err(|error| format!("failed to normalize sprint code: {error}"))?;
+    let mut report_path = reports_dir(&app, &conn)?;
+    report_path.push(format!(
+        "report-{}-{}.{extension}",
+        slugify(&sprint.name),
+        Utc::now().format("%Y%m%d%H%M%S")
+    ));
+    drop(conn);
+
+    if let ReportFormat::Pdf = format {
+        write_pdf_report(&content, &report_path)?;
+    } else {
+        fs::write(&report_path, &content).map_err(|error| {
+            format!(
+                "unable to write report file {}: {error}",
+                report_path.display()
+            )
+        })?;
     }
 
-    Ok(())
+    Ok(ReportOutput {
+        markdown: content.clone(),
+        content,
+        file_path: report_path.to_string_lossy().to_string(),
+        total_items: filtered.len(),
+    })
 }
 
-fn open_db(app: &AppHandle) -> Result<Connection, String> {
-    let db_path = db_file_path(app)?;
-    let mut conn = Connection::open(&db_path)
-        .map_err(|error| format!("unable to open database {}: {error}", db_path.display()))?;
+#[tauri::command]
+fn generate_combined_report(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: CombinedReportInput,
+) -> Result<ReportOutput, String> {
+    match parse_report_format(input.format.as_deref())? {
+        ReportFormat::Markdown => {}
+        _ => return Err("combined reports support markdown only".to_string()),
+    }
 
-    init_schema(&conn)?;
-    migrate_legacy_json_if_needed(app, &mut conn)?;
-    ensure_default_categories_db(&conn)?;
-    migrate_preview_category_db(&conn)?;
-    ensure_sprint_codes_db(&conn)?;
+    let mut seen = HashSet::new();
+    let sprint_ids = input
+        .sprint_ids
+        .iter()
+        .map(|sprint_id| sprint_id.trim())
+        .filter(|sprint_id| !sprint_id.is_empty() && seen.insert(*sprint_id))
+        .collect::<Vec<_>>();
 
-    Ok(conn)
-}
+    if sprint_ids.is_empty() {
+        return Err("at least one sprint id is required".to_string());
+    }
 
-fn category_name_exists(
-    conn: &Connection,
-    name: &str,
-    excluding_id: Option<&str>,
-) -> Result<bool, String> {
-    if let Some(excluding) = excluding_id {
-        let existing = conn
-            .query_row(
-                "SELECT id FROM categories WHERE lower(name) = lower(?1) AND id <> ?2 LIMIT 1",
-                params![name, excluding],
-                |row| row.get::<_, String>(0),
-            )
-            .optional()
-            .map_err(|error| format!("failed to check category uniqueness: {error}"))?;
+    let (from_date, to_date) =
+        report_date_range(input.from_date.as_deref(), input.to_date.as_deref())?;
 
-        Ok(existing.is_some())
-    } else {
-        let existing = conn
-            .query_row(
-                "SELECT id FROM categories WHERE lower(name) = lower(?1) LIMIT 1",
-                params![name],
-                |row| row.get::<_, String>(0),
-            )
-            .optional()
-            .map_err(|error| format!("failed to check category uniqueness: {error}"))?;
+    let conn = db.conn()?;
 
-        Ok(existing.is_some())
+    let mut sprints = Vec::new();
+    let mut missing = Vec::new();
+    for sprint_id in sprint_ids {
+        match get_sprint_db(&conn, sprint_id)? {
+            Some(sprint) => sprints.push(sprint),
+            None => missing.push(sprint_id.to_string()),
+        }
     }
-}
 
-fn category_exists(conn: &Connection, id: &str) -> Result<bool, String> {
-    let existing = conn
-        .query_row(
-            "SELECT 1 FROM categories WHERE id = ?1 LIMIT 1",
-            params![id],
-            |row| row.get::<_, i64>(0),
-        )
-        .optional()
-        .map_err(|error| format!("failed to check category existence: {error}"))?;
+    if !missing.is_empty() {
+        return Err(format!("sprints not found: {}", missing.join(", ")));
+    }
 
-    Ok(existing.is_some())
-}
+    sprints.sort_by(|left, right| {
+        left.start_date
+            .cmp(&right.start_date)
+            .then(left.created_at.cmp(&right.created_at))
+    });
+
+    let category_name_map: HashMap<String, String> = list_categories_db(&conn)?
+        .into_iter()
+        .map(|category| (category.id, category.name))
+        .collect();
+
+    let mut content = String::new();
+    content.push_str("# Combined Report\n\n");
+    content.push_str(&format!("- Sprints: {}\n", sprints.len()));
+    content.push_str(&format!("- Exported At: {}\n\n", now()));
+
+    let mut total_items = 0;
+    for sprint in &sprints {
+        let mut entries = list_entries_for_sprint_db(
+            &conn,
+            sprint.id.as_str(),
+            from_date.as_deref(),
+            to_date.as_deref(),
+        )?;
+        sort_report_entries(&mut entries);
+        total_items += entries.len();
+
+        content.push_str(&format!("## {} \u{2014} {}\n\n", sprint.code, sprint.name));
+        let grouped = group_entries(&entries, &category_name_map, ReportGrouping::Day);
+        if grouped.is_empty() {
+            content.push_str("No items found for the selected filters.\n\n");
+        } else {
+            push_markdown_groups(&mut content, grouped, ReportGrouping::Day, false, 3);
+        }
+    }
+
+    let mut report_path = reports_dir(&app, &conn)?;
+    report_path.push(format!(
+        "report-combined-{}.md",
+        Utc::now().format("%Y%m%d%H%M%S")
+    ));
 
-fn sprint_exists(conn: &Connection, id: &str) -> Result<bool, String> {
-    let existing = conn
-        .query_row(
-            "SELECT 1 FROM sprints WHERE id = ?1 LIMIT 1",
-            params![id],
-            |row| row.get::<_, i64>(0),
+    fs::write(&report_path, &content).map_err(|error| {
+        format!(
+            "unable to write report file {}: {error}",
+            report_path.display()
         )
-        .optional()
-        .map_err(|error| format!("failed to check sprint existence: {error}"))?;
+    })?;
 
-    Ok(existing.is_some())
+    Ok(ReportOutput {
+        markdown: content.clone(),
+        content,
+        file_path: report_path.to_string_lossy().to_string(),
+        total_items,
+    })
 }
 
-fn list_categories_db(conn: &Connection) -> Result<Vec<Category>, String> {
-    let mut stmt = conn
-        .prepare("SELECT id, name, created_at FROM categories ORDER BY created_at")
-        .map_err(|error| format!("failed to prepare categories query: {error}"))?;
+#[tauri::command]
+fn export_data_json(app: AppHandle, db: State<'_, DbState>) -> Result<String, String> {
+    let conn = db.conn()?;
 
-    let rows = stmt
-        .query_map([], |row| {
-            Ok(Category {
-                id: row.get(0)?,
-                name: row.get(1)?,
-                created_at: row.get(2)?,
-            })
-        })
-        .map_err(|error| format!("failed to query categories: {error}"))?;
+    let mut entries = list_all_entries_db(&conn)?;
+    attach_entry_tags_db(&conn, None, &mut entries)?;
 
-    let items = rows
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|error| format!("failed to collect categories: {error}"))?;
+    let data = AppData {
+        categories: list_categories_db(&conn)?,
+        sprints: list_sprints_db(&conn, true)?,
+        entries,
+    };
 
-    Ok(items)
-}
+    let json = serde_json::to_string_pretty(&data)
+        .map_err(|error| format!("failed to serialize export data: {error}"))?;
 
-fn list_sprints_db(conn: &Connection) -> Result<Vec<Sprint>, String> {
-    let mut stmt = conn
-        .prepare(
-            "SELECT id, code, name, start_date, end_date, created_at FROM sprints ORDER BY created_at",
+    let mut export_path = reports_dir(&app, &conn)?;
+    export_path.push(format!(
+        "devlog-export-{}.json",
+        Utc::now().format("%Y%m%d%H%M%S")
+    ));
+
+    fs::write(&export_path, json).map_err(|error| {
+        format!(
+            "unable to write export file {}: {error}",
+            export_path.display()
         )
-        .map_err(|error| format!("failed to prepare sprints query: {error}"))?;
+    })?;
 
-    let rows = stmt
-        .query_map([], |row| {
-            Ok(Sprint {
-                id: row.get(0)?,
-                code: row.get(1)?,
-                name: row.get(2)?,
-                start_date: row.get(3)?,
-                end_date: row.get(4)?,
-                created_at: row.get(5)?,
-            })
-        })
-        .map_err(|error| format!("failed to query sprints: {error}"))?;
+    Ok(export_path.to_string_lossy().to_string())
+}
 
-    let items = rows
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|error| format!("failed to collect sprints: {error}"))?;
+#[tauri::command]
+fn export_entries_jsonl(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: ExportJsonlInput,
+) -> Result<ExportJsonlOutput, String> {
+    let conn = db.conn()?;
+
+    let export_path = match input
+        .out_path
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        Some(value) => {
+            let path = PathBuf::from(value);
+            if !path.is_absolute() {
+                return Err("out_path must be an absolute path".to_string());
+            }
+            path
+        }
+        None => {
+            let mut path = reports_dir(&app, &conn)?;
+            path.push(format!(
+                "devlog-entries-{}.jsonl",
+                Utc::now().format("%Y%m%d%H%M%S")
+            ));
+            path
+        }
+    };
 
-    Ok(items)
-}
+    let file = fs::File::create(&export_path).map_err(|error| {
+        format!(
+            "unable to create export file {}: {error}",
+            export_path.display()
+        )
+    })?;
+    let mut writer = BufWriter::new(file);
 
-fn list_entries_for_sprint_db(
-    conn: &Connection,
-    sprint_id: &str,
-) -> Result<Vec<DailyEntry>, String> {
     let mut stmt = conn
         .prepare(
-            "SELECT id, sprint_id, date, category_id, title, details, created_at
+            "SELECT id, sprint_id, date, category_id, title, details, created_at, updated_at, link, seq
              FROM entries
-             WHERE sprint_id = ?1
-             ORDER BY date, category_id, created_at",
+             WHERE ?1 IS NULL OR sprint_id = ?1
+             ORDER BY date, category_id, order_index, created_at",
         )
-        .map_err(|error| format!("failed to prepare entries query: {error}"))?;
+        .map_err(|error| format!("failed to prepare entries export query: {error}"))?;
 
     let rows = stmt
-        .query_map(params![sprint_id], |row| {
-            Ok(DailyEntry {
-                id: row.get(0)?,
-                sprint_id: row.get(1)?,
-                date: row.get(2)?,
-                category_id: row.get(3)?,
-                title: row.get(4)?,
-                details: row.get(5)?,
-                created_at: row.get(6)?,
-            })
-        })
-        .map_err(|error| format!("failed to query entries: {error}"))?;
+        .query_map(params![input.sprint_id], entry_from_row)
+        .map_err(|error| format!("failed to query entries for export: {error}"))?;
+
+    let mut rows_written = 0;
+    for row in rows {
+        let mut entry = row.map_err(|error| format!("failed to read entry for export: {error}"))?;
+        entry.tags = entry_tags_db(&conn, entry.id.as_str())?;
+
+        serde_json::to_writer(&mut writer, &entry)
+            .map_err(|error| format!("failed to serialize entry {}: {error}", entry.id))?;
+        writer
+            .write_all(b"\n")
+            .map_err(|error| format!("failed to write export file: {error}"))?;
+        rows_written += 1;
+    }
 
-    let items = rows
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|error| format!("failed to collect entries: {error}"))?;
+    writer
+        .flush()
+        .map_err(|error| format!("failed to write export file: {error}"))?;
 
-    Ok(items)
+    Ok(ExportJsonlOutput {
+        file_path: export_path.to_string_lossy().to_string(),
+        rows_written,
+    })
 }
 
-fn next_sprint_code_db(conn: &Connection) -> Result<String, String> {
-    let mut stmt = conn
-        .prepare("SELECT code, name FROM sprints")
-        .map_err(|error| format!("failed to prepare next sprint code query: {error}"))?;
-
-    let rows = stmt
-        .query_map([], |row| {
-            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
-        })
-        .map_err(|error| format!("failed to query sprint codes: {error}"))?;
-
-    let pairs = rows
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|error| format!("failed to collect sprint codes: {error}"))?;
-
-    let highest = pairs
-        .iter()
-        .filter_map(|(code, name)| sprint_number(code).or_else(|| sprint_number(name)))
-        .max()
-        .unwrap_or(0);
-
-    Ok(format_sprint_code(highest + 1))
+#[tauri::command]
+fn get_data_path(app: AppHandle) -> Result<String, String> {
+    let path = db_file_path(&app)?;
+    Ok(path.to_string_lossy().to_string())
 }
 
 #[tauri::command]
-fn list_categories(app: AppHandle) -> Result<Vec<Category>, String> {
-    let conn = open_db(&app)?;
-    list_categories_db(&conn)
+fn get_report_directory(app: AppHandle, db: State<'_, DbState>) -> Result<String, String> {
+    let conn = db.conn()?;
+    let path = reports_dir(&app, &conn)?;
+    Ok(path.to_string_lossy().to_string())
 }
 
 #[tauri::command]
-fn create_category(app: AppHandle, input: NewCategoryInput) -> Result<Category, String> {
-    let name = input.name.trim();
-    if name.is_empty() {
-        return Err("category name is required".to_string());
-    }
+fn set_report_directory(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    path: Option<String>,
+) -> Result<String, String> {
+    let conn = db.conn()?;
+
+    let custom = path
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+
+    match custom {
+        Some(value) => {
+            let directory = PathBuf::from(value);
+            if !directory.is_absolute() {
+                return Err("report directory must be an absolute path".to_string());
+            }
 
-    let conn = open_db(&app)?;
-    if category_name_exists(&conn, name, None)? {
-        return Err("category name already exists".to_string());
+            ensure_directory_writable(&directory)?;
+            set_setting_db(&conn, REPORT_DIRECTORY_SETTING, Some(value))?;
+        }
+        None => set_setting_db(&conn, REPORT_DIRECTORY_SETTING, None)?,
     }
 
-    let category = Category {
-        id: format!("cat-{}-{}", slugify(name), Utc::now().timestamp_millis()),
-        name: name.to_string(),
-        created_at: now(),
-    };
-
-    conn.execute(
-        "INSERT INTO categories (id, name, created_at) VALUES (?1, ?2, ?3)",
-        params![category.id, category.name, category.created_at],
-    )
-    .map_err(|error| format!("failed to create category: {error}"))?;
-
-    Ok(category)
+    let path = reports_dir(&app, &conn)?;
+    Ok(path.to_string_lossy().to_string())
 }
 
 #[tauri::command]
-fn update_category(app: AppHandle, input: UpdateCategoryInput) -> Result<Category, String> {
-    let id = input.id.trim();
-    let name = input.name.trim();
-
-    if id.is_empty() {
-        return Err("category id is required".to_string());
-    }
-
-    if name.is_empty() {
-        return Err("category name is required".to_string());
-    }
-
-    let conn = open_db(&app)?;
-
-    if category_name_exists(&conn, name, Some(id))? {
-        return Err("category name already exists".to_string());
-    }
-
-    let affected = conn
-        .execute(
-            "UPDATE categories SET name = ?1 WHERE id = ?2",
-            params![name, id],
-        )
-        .map_err(|error| format!("failed to update category: {error}"))?;
-
-    if affected == 0 {
-        return Err("category not found".to_string());
-    }
-
-    conn.query_row(
-        "SELECT id, name, created_at FROM categories WHERE id = ?1",
-        params![id],
-        |row| {
-            Ok(Category {
-                id: row.get(0)?,
-                name: row.get(1)?,
-                created_at: row.get(2)?,
-            })
-        },
-    )
-    .map_err(|error| format!("failed to load updated category: {error}"))
+fn get_default_sprint_duration(db: State<'_, DbState>) -> Result<i64, String> {
+    let conn = db.conn()?;
+    default_sprint_duration_db(&conn)
 }
 
 #[tauri::command]
-fn delete_category(app: AppHandle, input: DeleteCategoryInput) -> Result<(), String> {
-    let category_id = input.id.trim();
-    if category_id.is_empty() {
-        return Err("category id is required".to_string());
+fn set_default_sprint_duration(
+    db: State<'_, DbState>,
+    duration_days: Option<i64>,
+) -> Result<i64, String> {
+    let conn = db.conn()?;
+
+    match duration_days {
+        Some(value) => {
+            let value = validate_sprint_duration(value)?;
+            set_setting_db(
+                &conn,
+                DEFAULT_SPRINT_DURATION_SETTING,
+                Some(&value.to_string()),
+            )?;
+        }
+        None => set_setting_db(&conn, DEFAULT_SPRINT_DURATION_SETTING, None)?,
     }
 
-    let conn = open_db(&app)?;
-
-    let total_categories: i64 = conn
-        .query_row("SELECT COUNT(*) FROM categories", [], |row| row.get(0))
-        .map_err(|error| format!("failed to count categories: {error}"))?;
+    default_sprint_duration_db(&conn)
+}
 
-    if total_categories <= 1 {
-        return Err("at least one category is required".to_string());
-    }
+#[tauri::command]
+fn get_max_title_length(db: State<'_, DbState>) -> Result<usize, String> {
+    let conn = db.conn()?;
+    max_title_length_db(&conn)
+}
 
-    if !category_exists(&conn, category_id)? {
-        return Err("category not found".to_string());
+#[tauri::command]
+fn set_max_title_length(
+    db: State<'_, DbState>,
+    max_length: Option<usize>,
+) -> Result<usize, String> {
+    let conn = db.conn()?;
+
+    match max_length {
+        Some(0) => return Err("max_length must be greater than 0".to_string()),
+        Some(value) => set_setting_db(&conn, MAX_TITLE_LENGTH_SETTING, Some(&value.to_string()))?,
+        None => set_setting_db(&conn, MAX_TITLE_LENGTH_SETTING, None)?,
     }
 
-    let used_count: i64 = conn
-        .query_row(
-            "SELECT COUNT(*) FROM entries WHERE category_id = ?1",
-            params![category_id],
-            |row| row.get(0),
-        )
-        .map_err(|error| format!("failed to count category usage: {error}"))?;
-
-    if used_count > 0 {
-        let replacement_from_input = input
-            .replacement_category_id
-            .as_ref()
-            .map(|value| value.trim())
-            .filter(|value| !value.is_empty())
-            .map(|value| value.to_string());
+    max_title_length_db(&conn)
+}
 
-        let replacement_id = if let Some(value) = replacement_from_input {
-            value
+fn normalize_shortcut_accelerator(value: Option<String>) -> Option<String> {
+    value.and_then(|raw| {
+        let trimmed = raw.trim();
+        if trimmed.is_empty() {
+            None
         } else {
-            conn.query_row(
-                "SELECT id FROM categories WHERE id <> ?1 ORDER BY created_at LIMIT 1",
-                params![category_id],
-                |row| row.get::<_, String>(0),
-            )
-            .optional()
-            .map_err(|error| format!("failed to find replacement category: {error}"))?
-            .ok_or_else(|| "no replacement category available".to_string())?
-        };
-
-        if replacement_id == category_id {
-            return Err("replacement category must be different".to_string());
+            Some(trimmed.to_string())
         }
+    })
+}
 
-        if !category_exists(&conn, replacement_id.as_str())? {
-            return Err("replacement category not found".to_string());
-        }
+fn build_tray_menu<R: Runtime, M: Manager<R>>(
+    app: &M,
+    add_item_shortcut: Option<&str>,
+    add_sprint_shortcut: Option<&str>,
+) -> tauri::Result<tauri::menu::Menu<R>> {
+    let add_item = MenuItem::with_id(
+        app,
+        TRAY_MENU_ADD_ITEM_ID,
+        "Add New Item to Current Sprint",
+        true,
+        add_item_shortcut,
+    )?;
+    let add_sprint = MenuItem::with_id(
+        app,
+        TRAY_MENU_ADD_SPRINT_ID,
+        "Add New Sprint",
+        true,
+        add_sprint_shortcut,
+    )?;
+    let quit = MenuItem::with_id(app, TRAY_MENU_QUIT_ID, "Quit", true, None::<&str>)?;
 
-        conn.execute(
-            "UPDATE entries SET category_id = ?1 WHERE category_id = ?2",
-            params![replacement_id, category_id],
-        )
-        .map_err(|error| format!("failed to reassign category entries: {error}"))?;
+    MenuBuilder::new(app)
+        .item(&add_item)
+        .item(&add_sprint)
+        .separator()
+        .item(&quit)
+        .build()
+}
+
+fn show_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
     }
+}
 
-    let affected = conn
-        .execute("DELETE FROM categories WHERE id = ?1", params![category_id])
-        .map_err(|error| format!("failed to delete category: {error}"))?;
+fn emit_tray_action(app: &AppHandle, action: &str) {
+    show_main_window(app);
+    let _ = app.emit("tray-action", action);
+}
 
-    if affected == 0 {
-        return Err("category not found".to_string());
-    }
+fn emit_data_changed(app: &AppHandle, kind: &str) {
+    let _ = app.emit("data-changed", kind);
+}
 
-    Ok(())
+fn load_menubar_settings_db(conn: &Connection) -> Result<MenubarSettings, String> {
+    let defaults = MenubarSettings::default();
+
+    let show_icon = get_setting_db(conn, MENUBAR_SHOW_ICON_SETTING)?
+        .map(|value| value != "false")
+        .unwrap_or(defaults.show_icon);
+    let add_item_shortcut =
+        normalize_shortcut_accelerator(get_setting_db(conn, ADD_ITEM_SHORTCUT_SETTING)?)
+            .unwrap_or(defaults.add_item_shortcut);
+    let add_sprint_shortcut =
+        normalize_shortcut_accelerator(get_setting_db(conn, ADD_SPRINT_SHORTCUT_SETTING)?)
+            .or(defaults.add_sprint_shortcut);
+
+    Ok(MenubarSettings {
+        show_icon,
+        add_item_shortcut,
+        add_sprint_shortcut,
+    })
 }
 
 #[tauri::command]
-fn list_sprints(app: AppHandle) -> Result<Vec<Sprint>, String> {
-    let conn = open_db(&app)?;
-    list_sprints_db(&conn)
+fn get_menubar_settings(db: State<'_, DbState>) -> Result<MenubarSettings, String> {
+    let conn = db.conn()?;
+    load_menubar_settings_db(&conn)
 }
 
 #[tauri::command]
-fn create_sprint(app: AppHandle, input: NewSprintInput) -> Result<Sprint, String> {
-    let start_date = input.start_date.trim();
-    if start_date.is_empty() {
-        return Err("start_date is required".to_string());
-    }
+fn update_menubar_settings(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: MenubarSettingsInput,
+) -> Result<(), String> {
+    let shortcut = normalize_shortcut_accelerator(input.add_item_shortcut);
+    let sprint_shortcut = match input.add_sprint_shortcut {
+        Some(raw) => normalize_shortcut_accelerator(Some(raw)),
+        None => {
+            let conn = db.conn()?;
+            normalize_shortcut_accelerator(get_setting_db(&conn, ADD_SPRINT_SHORTCUT_SETTING)?)
+        }
+    };
 
-    let parsed_start = NaiveDate::parse_from_str(start_date, "%Y-%m-%d")
-        .map_err(|_| "start_date must be in YYYY-MM-DD format".to_string())?;
-    let duration_days = input.duration_days.unwrap_or(14);
-    if duration_days != 7 && duration_days != 14 {
-        return Err("duration_days must be 7 or 14".to_string());
+    let item_accelerator = shortcut.as_deref().unwrap_or(DEFAULT_ADD_ITEM_SHORTCUT);
+    if sprint_shortcut
+        .as_deref()
+        .is_some_and(|sprint| sprint.eq_ignore_ascii_case(item_accelerator))
+    {
+        return Err("shortcuts must be distinct".to_string());
     }
-    let calculated_end = (parsed_start + Duration::days(duration_days - 1))
-        .format("%Y-%m-%d")
-        .to_string();
 
-    let conn = open_db(&app)?;
-    let code = next_sprint_code_db(&conn)?;
+    let tray_menu = build_tray_menu(&app, Some(item_accelerator), sprint_shortcut.as_deref())
+        .map_err(|error| format!("failed to rebuild tray menu: {error}"))?;
 
-    let display_name = input
-        .name
-        .as_ref()
-        .map(|name| name.trim().to_string())
-        .filter(|name| !name.is_empty())
-        .unwrap_or_else(|| code.clone());
+    let tray_icon = app
+        .tray_by_id(TRAY_ICON_ID)
+        .ok_or_else(|| "tray icon is not available".to_string())?;
 
-    let sprint = Sprint {
-        id: next_id("sprint"),
-        code,
-        name: display_name,
-        start_date: start_date.to_string(),
-        end_date: Some(calculated_end),
-        created_at: now(),
-    };
+    tray_icon
+        .set_menu(Some(tray_menu))
+        .map_err(|error| format!("failed to update tray menu: {error}"))?;
 
-    conn.execute(
-        "INSERT INTO sprints (id, code, name, start_date, end_date, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-        params![
-            sprint.id,
-            sprint.code,
-            sprint.name,
-            sprint.start_date,
-            sprint.end_date,
-            sprint.created_at
-        ],
-    )
-    .map_err(|error| format!("failed to create sprint: {error}"))?;
+    tray_icon
+        .set_visible(input.show_icon)
+        .map_err(|error| format!("failed to update tray icon visibility: {error}"))?;
 
-    Ok(sprint)
+    let conn = db.conn()?;
+    set_setting_db(
+        &conn,
+        MENUBAR_SHOW_ICON_SETTING,
+        Some(if input.show_icon { "true" } else { "false" }),
+    )?;
+    set_setting_db(&conn, ADD_ITEM_SHORTCUT_SETTING, shortcut.as_deref())?;
+    set_setting_db(
+        &conn,
+        ADD_SPRINT_SHORTCUT_SETTING,
+        sprint_shortcut.as_deref(),
+    )?;
+
+    Ok(())
 }
 
 #[tauri::command]
-fn update_sprint_name(app: AppHandle, input: UpdateSprintNameInput) -> Result<Sprint, String> {
-    let sprint_id = input.id.trim();
-    let name = input.name.trim();
+fn force_import_legacy(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: ForceImportLegacyInput,
+) -> Result<LegacyImportReport, String> {
+    let policy = match input.on_conflict.trim().to_ascii_lowercase().as_str() {
+        "skip" => LegacyConflictPolicy::Skip,
+        "replace" => LegacyConflictPolicy::Replace,
+        _ => return Err("on_conflict must be skip or replace".to_string()),
+    };
 
-    if sprint_id.is_empty() {
-        return Err("sprint id is required".to_string());
+    let path = PathBuf::from(input.path.trim());
+    if !path.is_file() {
+        return Err(format!("legacy data file not found: {}", path.display()));
     }
 
-    if name.is_empty() {
-        return Err("sprint name is required".to_string());
+    let legacy = read_legacy_data(&path)?;
+    let mut conn = db.conn()?;
+    let report = import_legacy_data_db(&mut conn, legacy, policy)?;
+
+    emit_data_changed(&app, "all");
+    Ok(report)
+}
+
+#[tauri::command]
+fn validate_legacy_json(input: ValidateLegacyInput) -> Result<ImportPreview, String> {
+    let path = PathBuf::from(input.path.trim());
+    if !path.is_file() {
+        return Err(format!("legacy data file not found: {}", path.display()));
     }
 
-    let conn = open_db(&app)?;
+    let legacy = read_legacy_data(&path)?;
 
-    let affected = conn
-        .execute(
-            "UPDATE sprints SET name = ?1 WHERE id = ?2",
-            params![name, sprint_id],
-        )
-        .map_err(|error| format!("failed to update sprint name: {error}"))?;
+    let sprint_ids = legacy
+        .sprints
+        .iter()
+        .filter(|sprint| !sprint.id.trim().is_empty() && !sprint.start_date.trim().is_empty())
+        .map(|sprint| sprint.id.as_str())
+        .collect::<HashSet<_>>();
 
-    if affected == 0 {
-        return Err("sprint not found".to_string());
+    let mut preview = ImportPreview {
+        categories: legacy
+            .categories
+            .iter()
+            .filter(|category| !category.id.trim().is_empty() && !category.name.trim().is_empty())
+            .count(),
+        sprints: sprint_ids.len(),
+        entries: legacy.entries.len(),
+        entries_missing_sprint: 0,
+        entries_missing_category: 0,
+        invalid_dates: 0,
+    };
+
+    for entry in &legacy.entries {
+        if !sprint_ids.contains(entry.sprint_id.as_str()) {
+            preview.entries_missing_sprint += 1;
+        }
+
+        if entry.category_id.trim().is_empty() {
+            preview.entries_missing_category += 1;
+        }
+
+        if NaiveDate::parse_from_str(entry.date.trim(), "%Y-%m-%d").is_err() {
+            preview.invalid_dates += 1;
+        }
     }
 
-    conn.query_row(
-        "SELECT id, code, name, start_date, end_date, created_at FROM sprints WHERE id = ?1",
-        params![sprint_id],
-        |row| {
-            Ok(Sprint {
-                id: row.get(0)?,
-                code: row.get(1)?,
-                name: row.get(2)?,
-                start_date: row.get(3)?,
-                end_date: row.get(4)?,
-                created_at: row.get(5)?,
-            })
-        },
-    )
-    .map_err(|error| format!("failed to fetch updated sprint: {error}"))
+    Ok(preview)
 }
 
 #[tauri::command]
-fn delete_sprint(app: AppHandle, input: DeleteSprintInput) -> Result<(), String> {
-    let sprint_id = input.id.trim();
-    if sprint_id.is_empty() {
-        return Err("sprint id is required".to_string());
+fn preview_legacy_categories(
+    db: State<'_, DbState>,
+    input: ValidateLegacyInput,
+) -> Result<Vec<String>, String> {
+    let path = PathBuf::from(input.path.trim());
+    if !path.is_file() {
+        return Err(format!("legacy data file not found: {}", path.display()));
     }
 
-    let conn = open_db(&app)?;
-    let affected = conn
-        .execute("DELETE FROM sprints WHERE id = ?1", params![sprint_id])
-        .map_err(|error| format!("failed to delete sprint: {error}"))?;
+    let mut legacy = read_legacy_data(&path)?;
+    ensure_default_categories(&mut legacy);
 
-    if affected == 0 {
-        return Err("sprint not found".to_string());
-    }
+    let conn = db.conn()?;
+    let mut known_category_ids = list_categories_db(&conn)?
+        .into_iter()
+        .map(|category| category.id)
+        .collect::<HashSet<_>>();
+    known_category_ids.extend(legacy.categories.into_iter().map(|category| category.id));
 
-    Ok(())
+    let names = legacy
+        .entries
+        .iter()
+        .map(|entry| entry.category_id.trim())
+        .filter(|category_id| !category_id.is_empty() && !known_category_ids.contains(*category_id))
+        .map(humanize_category_id)
+        .collect::<BTreeSet<_>>();
+
+    Ok(names.into_iter().collect())
 }
 
 #[tauri::command]
-fn list_entries_for_sprint(app: AppHandle, sprint_id: String) -> Result<Vec<DailyEntry>, String> {
-    let conn = open_db(&app)?;
-    list_entries_for_sprint_db(&conn, sprint_id.as_str())
+fn get_db_recovery(recovery: State<'_, DbRecoveryState>) -> Result<Option<DbRecovery>, String> {
+    recovery
+        .0
+        .lock()
+        .map(|recovery| recovery.clone())
+        .map_err(|_| "database recovery state is unavailable".to_string())
 }
 
 #[tauri::command]
-fn add_daily_entry(app: AppHandle, input: NewDailyEntryInput) -> Result<DailyEntry, String> {
-    let title = input.title.trim();
-
-    if title.is_empty() {
-        return Err("title is required".to_string());
-    }
-
-    if input.date.trim().is_empty() {
-        return Err("date is required".to_string());
-    }
-
-    if input.category_id.trim().is_empty() {
-        return Err("category_id is required".to_string());
-    }
+fn backup_database(app: AppHandle, db: State<'_, DbState>) -> Result<String, String> {
+    let conn = db.conn()?;
 
-    let conn = open_db(&app)?;
+    let mut backup_path = backups_dir(&app)?;
+    backup_path.push(format!(
+        "devlog-{}.sqlite",
+        Utc::now().format("%Y%m%d%H%M%S")
+    ));
 
-    if !sprint_exists(&conn, input.sprint_id.as_str())? {
-        return Err("the selected sprint does not exist".to_string());
-    }
+    conn.backup(DatabaseName::Main, &backup_path, None::<fn(Progress)>)
+        .map_err(|error| {
+            format!(
+                "failed to back up database to {}: {error}",
+                backup_path.display()
+            )
+        })?;
 
-    if !category_exists(&conn, input.category_id.as_str())? {
-        return Err("the selected category does not exist".to_string());
-    }
+    Ok(backup_path.to_string_lossy().to_string())
+}
 
-    let entry = DailyEntry {
-        id: next_id("entry"),
-        sprint_id: input.sprint_id,
-        date: input.date,
-        category_id: input.category_id,
-        title: title.to_string(),
-        details: input.details.and_then(|value| {
-            let trimmed = value.trim().to_string();
-            if trimmed.is_empty() {
-                None
-            } else {
-                Some(trimmed)
-            }
-        }),
-        created_at: now(),
+fn is_devlog_database(path: &Path) -> bool {
+    let Ok(source) = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY) else {
+        return false;
     };
 
-    conn.execute(
-        "INSERT INTO entries (id, sprint_id, date, category_id, title, details, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
-        params![
-            entry.id,
-            entry.sprint_id,
-            entry.date,
-            entry.category_id,
-            entry.title,
-            entry.details,
-            entry.created_at
-        ],
-    )
-    .map_err(|error| format!("failed to add entry: {error}"))?;
-
-    Ok(entry)
+    source
+        .query_row(
+            "SELECT COUNT(*) FROM sqlite_master
+             WHERE type = 'table' AND name IN ('categories', 'sprints', 'entries')",
+            [],
+            |row| row.get::<_, i64>(0),
+        )
+        .map(|count| count == 3)
+        .unwrap_or(false)
 }
 
 #[tauri::command]
-fn update_daily_entry(app: AppHandle, input: UpdateDailyEntryInput) -> Result<DailyEntry, String> {
-    let entry_id = input.id.trim();
-    let date = input.date.trim();
-    let category_id = input.category_id.trim();
-    let title = input.title.trim();
-
-    if entry_id.is_empty() {
-        return Err("entry id is required".to_string());
-    }
-
-    if title.is_empty() {
-        return Err("title is required".to_string());
-    }
-
-    if date.is_empty() {
-        return Err("date is required".to_string());
+fn restore_database(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: RestoreInput,
+) -> Result<(), String> {
+    let raw_path = input.path.trim();
+    if raw_path.is_empty() {
+        return Err("backup path is required".to_string());
     }
 
-    if category_id.is_empty() {
-        return Err("category_id is required".to_string());
+    let source_path = PathBuf::from(raw_path);
+    if !source_path.is_file() {
+        return Err(format!("backup file not found: {}", source_path.display()));
     }
 
-    let conn = open_db(&app)?;
-
-    if !category_exists(&conn, category_id)? {
-        return Err("the selected category does not exist".to_string());
+    if !is_devlog_database(&source_path) {
+        return Err("not a valid devlog database".to_string());
     }
 
-    let normalized_details = input.details.and_then(|value| {
-        let trimmed = value.trim().to_string();
-        if trimmed.is_empty() {
-            None
-        } else {
-            Some(trimmed)
-        }
-    });
+    let mut conn = db.conn()?;
+    conn.restore(DatabaseName::Main, &source_path, None::<fn(Progress)>)
+        .map_err(|error| {
+            format!(
+                "failed to restore database from {}: {error}",
+                source_path.display()
+            )
+        })?;
 
-    let affected = conn
-        .execute(
-            "UPDATE entries
-             SET date = ?1, category_id = ?2, title = ?3, details = ?4
-             WHERE id = ?5",
-            params![date, category_id, title, normalized_details, entry_id],
-        )
-        .map_err(|error| format!("failed to update entry: {error}"))?;
+    initialize_db(&app, &mut conn)?;
+    emit_data_changed(&app, "all");
+    Ok(())
+}
 
-    if affected == 0 {
-        return Err("entry not found".to_string());
-    }
+#[tauri::command]
+fn get_schema_version(db: State<'_, DbState>) -> Result<i64, String> {
+    let conn = db.conn()?;
+    schema_version_db(&conn)
+}
 
+fn count_orphaned_entries_db(conn: &Connection) -> Result<OrphanReport, String> {
     conn.query_row(
-        "SELECT id, sprint_id, date, category_id, title, details, created_at
-         FROM entries
-         WHERE id = ?1",
-        params![entry_id],
+        "SELECT
+            (SELECT COUNT(*) FROM entries e
+             WHERE NOT EXISTS (SELECT 1 FROM sprints s WHERE s.id = e.sprint_id)),
+            (SELECT COUNT(*) FROM entries e
+             WHERE NOT EXISTS (SELECT 1 FROM categories c WHERE c.id = e.category_id))",
+        [],
         |row| {
-            Ok(DailyEntry {
-                id: row.get(0)?,
-                sprint_id: row.get(1)?,
-                date: row.get(2)?,
-                category_id: row.get(3)?,
-                title: row.get(4)?,
-                details: row.get(5)?,
-                created_at: row.get(6)?,
+            Ok(OrphanReport {
+                missing_sprint: row.get::<_, i64>(0)? as usize,
+                missing_category: row.get::<_, i64>(1)? as usize,
             })
         },
     )
-    .map_err(|error| format!("failed to fetch updated entry: {error}"))
+    .map_err(|error| format!("failed to count orphaned entries: {error}"))
 }
 
 #[tauri::command]
-fn delete_daily_entry(app: AppHandle, input: DeleteDailyEntryInput) -> Result<(), String> {
-    let entry_id = input.id.trim();
-    if entry_id.is_empty() {
-        return Err("entry id is required".to_string());
-    }
-
-    let conn = open_db(&app)?;
-    let affected = conn
-        .execute("DELETE FROM entries WHERE id = ?1", params![entry_id])
-        .map_err(|error| format!("failed to delete entry: {error}"))?;
-
-    if affected == 0 {
-        return Err("entry not found".to_string());
-    }
-
-    Ok(())
+fn find_orphaned_entries(db: State<'_, DbState>) -> Result<OrphanReport, String> {
+    let conn = db.conn()?;
+    count_orphaned_entries_db(&conn)
 }
 
 #[tauri::command]
-fn generate_report(app: AppHandle, input: ReportInput) -> Result<ReportOutput, String> {
-    let conn = open_db(&app)?;
-
-    let sprint = conn
-        .query_row(
-            "SELECT id, code, name, start_date, end_date, created_at FROM sprints WHERE id = ?1",
-            params![input.sprint_id],
-            |row| {
-                Ok(Sprint {
-                    id: row.get(0)?,
-                    code: row.get(1)?,
-                    name: row.get(2)?,
-                    start_date: row.get(3)?,
-                    end_date: row.get(4)?,
-                    created_at: row.get(5)?,
-                })
-            },
-        )
-        .optional()
-        .map_err(|error| format!("failed to read sprint for report: {error}"))?
-        .ok_or_else(|| "the selected sprint does not exist".to_string())?;
-
-    let category_filter = input.categories.and_then(|categories| {
-        if categories.is_empty() {
-            None
-        } else {
-            Some(categories)
-        }
-    });
+fn repair_orphaned_entries(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: RepairOrphansInput,
+) -> Result<OrphanReport, String> {
+    let default_category_id = input.default_category_id.trim();
+    if default_category_id.is_empty() {
+        return Err("default category id is required".to_string());
+    }
 
-    let category_set = category_filter
-        .as_ref()
-        .map(|categories| categories.iter().cloned().collect::<BTreeSet<_>>());
+    let mut conn = db.conn()?;
 
-    let categories = list_categories_db(&conn)?;
-    let category_name_map: HashMap<String, String> = categories
-        .iter()
-        .map(|category| (category.id.clone(), category.name.clone()))
-        .collect();
+    if !category_exists(&conn, default_category_id)? {
+        return Err("default category not found".to_string());
+    }
 
-    let mut filtered = list_entries_for_sprint_db(&conn, input.sprint_id.as_str())?
-        .into_iter()
-        .filter(|entry| within_range(&entry.date, &input.from_date, &input.to_date))
-        .filter(|entry| {
-            if let Some(set) = &category_set {
-                set.contains(&entry.category_id)
-            } else {
-                true
-            }
-        })
-        .collect::<Vec<_>>();
+    let tx = conn
+        .transaction()
+        .map_err(|error| format!("failed to start orphan repair transaction: {error}"))?;
 
-    filtered.sort_by(|left, right| {
-        left.date
-            .cmp(&right.date)
-            .then(left.category_id.cmp(&right.category_id))
-            .then(left.created_at.cmp(&right.created_at))
-    });
+    let missing_sprint = tx
+        .execute(
+            "DELETE FROM entries
+             WHERE NOT EXISTS (SELECT 1 FROM sprints s WHERE s.id = entries.sprint_id)",
+            [],
+        )
+        .map_err(|error| format!("failed to delete sprint orphans: {error}"))?;
+
+    let orphan_category_ids = {
+        let mut stmt = tx
+            .prepare(
+                "SELECT DISTINCT category_id FROM entries
+                 WHERE NOT EXISTS (SELECT 1 FROM categories c WHERE c.id = entries.category_id)
+                 ORDER BY category_id",
+            )
+            .map_err(|error| format!("failed to prepare category orphans query: {error}"))?;
 
-    let mut grouped: BTreeMap<String, BTreeMap<String, Vec<DailyEntry>>> = BTreeMap::new();
+        let rows = stmt
+            .query_map([], |row| row.get::<_, String>(0))
+            .map_err(|error| format!("failed to query category orphans: {error}"))?;
 
-    for entry in &filtered {
-        let category_label = category_name_map
-            .get(&entry.category_id)
-            .cloned()
-            .unwrap_or_else(|| entry.category_id.clone());
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|error| format!("failed to collect category orphans: {error}"))?
+    };
 
-        grouped
-            .entry(entry.date.clone())
-            .or_default()
-            .entry(category_label)
-            .or_default()
-            .push(entry.clone());
+    let mut missing_category = 0;
+    for orphan_category_id in orphan_category_ids {
+        missing_category +=
+            reassign_category_entries_db(&tx, &orphan_category_id, default_category_id)?;
     }
 
-    let mut markdown = String::new();
-    markdown.push_str(&format!("# Sprint Report: {}\n\n", sprint.name));
-    markdown.push_str(&format!("- Sprint Code: `{}`\n", sprint.code));
-    markdown.push_str(&format!(
-        "- Sprint Window: {} to {}\n",
-        sprint.start_date,
-        sprint
-            .end_date
-            .clone()
-            .unwrap_or_else(|| "open".to_string())
-    ));
-    markdown.push_str(&format!("- Exported At: {}\n\n", now()));
+    tx.commit()
+        .map_err(|error| format!("failed to commit orphan repair: {error}"))?;
 
-    if grouped.is_empty() {
-        markdown.push_str("No items found for the selected filters.\n");
-    } else {
-        for (date, by_category) in grouped {
-            markdown.push_str(&format!("## {}\n\n", date));
-            for (category_label, entries) in by_category {
-                markdown.push_str(&format!("### {}\n", category_label));
-                for item in entries {
-                    markdown.push_str(&format!("- {}", item.title));
-                    if let Some(details) = item.details {
-                        markdown.push_str(&format!(" - {}", details));
-                    }
-                    markdown.push('\n');
-                }
-                markdown.push('\n');
-            }
-        }
+    if missing_sprint > 0 || missing_category > 0 {
+        emit_data_changed(&app, "entries");
     }
 
-    let mut report_path = reports_dir(&app)?;
-    report_path.push(format!(
-        "report-{}-{}.md",
-        slugify(&sprint.name),
-        Utc::now().format("%Y%m%d%H%M%S")
-    ));
-
-    fs::write(&report_path, &markdown).map_err(|error| {
-        format!(
-            "unable to write report file {}: {error}",
-            report_path.display()
-        )
-    })?;
+    Ok(OrphanReport {
+        missing_sprint,
+        missing_category,
+    })
+}
 
-    Ok(ReportOutput {
-        markdown,
-        file_path: report_path.to_string_lossy().to_string(),
-        total_items: filtered.len(),
+fn count_rows_db(conn: &Connection, table: &str) -> Result<usize, String> {
+    conn.query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |row| {
+        row.get::<_, i64>(0)
     })
+    .map(|count| count as usize)
+    .map_err(|error| format!("failed to count {table}: {error}"))
 }
 
-#[tauri::command]
-fn get_data_path(app: AppHandle) -> Result<String, String> {
-    let path = db_file_path(&app)?;
-    Ok(path.to_string_lossy().to_string())
+fn pragma_value_db(conn: &Connection, pragma: &str) -> Result<i64, String> {
+    conn.query_row(&format!("PRAGMA {pragma}"), [], |row| row.get(0))
+        .map_err(|error| format!("failed to read {pragma}: {error}"))
 }
 
-fn normalize_shortcut_accelerator(value: Option<String>) -> Option<String> {
-    value.and_then(|raw| {
-        let trimmed = raw.trim();
-        if trimmed.is_empty() {
-            None
-        } else {
-            Some(trimmed.to_string())
-        }
+#[tauri::command]
+fn get_db_diagnostics(app: AppHandle, db: State<'_, DbState>) -> Result<DbDiagnostics, String> {
+    let db_path = db_file_path(&app)?;
+    let file_size_bytes = fs::metadata(&db_path)
+        .map(|metadata| metadata.len())
+        .map_err(|error| format!("failed to read database size: {error}"))?;
+
+    let conn = db.conn()?;
+
+    Ok(DbDiagnostics {
+        file_path: db_path.to_string_lossy().to_string(),
+        file_size_bytes,
+        categories: count_rows_db(&conn, "categories")?,
+        sprints: count_rows_db(&conn, "sprints")?,
+        entries: count_rows_db(&conn, "entries")?,
+        schema_version: schema_version_db(&conn)?,
+        page_count: pragma_value_db(&conn, "page_count")?,
+        freelist_count: pragma_value_db(&conn, "freelist_count")?,
     })
 }
 
-fn build_tray_menu<R: Runtime, M: Manager<R>>(
-    app: &M,
-    add_item_shortcut: Option<&str>,
-) -> tauri::Result<tauri::menu::Menu<R>> {
-    let add_item = MenuItem::with_id(
-        app,
-        TRAY_MENU_ADD_ITEM_ID,
-        "Add New Item to Current Sprint",
-        true,
-        add_item_shortcut,
-    )?;
-    let add_sprint = MenuItem::with_id(
-        app,
-        TRAY_MENU_ADD_SPRINT_ID,
-        "Add New Sprint",
-        true,
-        None::<&str>,
-    )?;
-    let quit = MenuItem::with_id(app, TRAY_MENU_QUIT_ID, "Quit", true, None::<&str>)?;
+#[tauri::command]
+fn vacuum_database(app: AppHandle, db: State<'_, DbState>) -> Result<VacuumResult, String> {
+    let db_path = db_file_path(&app)?;
+    let conn = db.conn()?;
 
-    MenuBuilder::new(app)
-        .item(&add_item)
-        .item(&add_sprint)
-        .separator()
-        .item(&quit)
-        .build()
+    if !conn.is_autocommit() {
+        return Err("cannot vacuum while a transaction is open".to_string());
+    }
+
+    let file_size = |path: &Path| {
+        fs::metadata(path)
+            .map(|metadata| metadata.len())
+            .map_err(|error| format!("failed to read database size: {error}"))
+    };
+
+    let bytes_before = file_size(&db_path)?;
+
+    conn.execute_batch("VACUUM")
+        .map_err(|error| format!("failed to vacuum database: {error}"))?;
+    drop(conn);
+
+    let bytes_after = file_size(&db_path)?;
+
+    Ok(VacuumResult {
+        bytes_before,
+        bytes_after,
+        reclaimed: bytes_before.saturating_sub(bytes_after),
+    })
 }
 
-fn show_main_window(app: &AppHandle) {
-    if let Some(window) = app.get_webview_window("main") {
-        let _ = window.unminimize();
-        let _ = window.show();
-        let _ = window.set_focus();
+#[tauri::command]
+fn list_reports(app: AppHandle, db: State<'_, DbState>) -> Result<Vec<ReportFile>, String> {
+    let directory = {
+        let conn = db.conn()?;
+        reports_dir(&app, &conn)?
+    };
+
+    let dir_entries =
+        fs::read_dir(&directory).map_err(|error| format!("failed to read reports dir: {error}"))?;
+
+    let mut reports = Vec::new();
+    for dir_entry in dir_entries.flatten() {
+        let file_name = dir_entry.file_name().to_string_lossy().to_string();
+        if !file_name.starts_with("report-") {
+            continue;
+        }
+
+        let Ok(metadata) = dir_entry.metadata() else {
+            continue;
+        };
+        if !metadata.is_file() {
+            continue;
+        }
+
+        let Ok(created) = metadata.created().or_else(|_| metadata.modified()) else {
+            continue;
+        };
+
+        let path = dir_entry.path();
+        let format = match path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_ascii_lowercase())
+            .as_deref()
+        {
+            Some("md") => "markdown".to_string(),
+            Some(extension) => extension.to_string(),
+            None => "unknown".to_string(),
+        };
+
+        reports.push(ReportFile {
+            file_name,
+            path: path.to_string_lossy().to_string(),
+            size_bytes: metadata.len(),
+            created: DateTime::<Utc>::from(created).to_rfc3339(),
+            format,
+        });
     }
-}
 
-fn emit_tray_action(app: &AppHandle, action: &str) {
-    show_main_window(app);
-    let _ = app.emit("tray-action", action);
+    reports.sort_by(|left, right| right.created.cmp(&left.created));
+    Ok(reports)
 }
 
 #[tauri::command]
-fn update_menubar_settings(app: AppHandle, input: MenubarSettingsInput) -> Result<(), String> {
-    let shortcut = normalize_shortcut_accelerator(input.add_item_shortcut);
-    let tray_menu = build_tray_menu(
-        &app,
-        shortcut.as_deref().or(Some(DEFAULT_ADD_ITEM_SHORTCUT)),
-    )
-    .map_err(|error| format!("failed to rebuild tray menu: {error}"))?;
+fn prune_reports(
+    app: AppHandle,
+    db: State<'_, DbState>,
+    input: PruneReportsInput,
+) -> Result<PruneResult, String> {
+    let directory = {
+        let conn = db.conn()?;
+        reports_dir(&app, &conn)?
+    };
 
-    let tray_icon = app
-        .tray_by_id(TRAY_ICON_ID)
-        .ok_or_else(|| "tray icon is not available".to_string())?;
+    let max_age = std::time::Duration::from_secs(
+        input
+            .older_than_days
+            .unwrap_or(DEFAULT_PRUNE_REPORTS_DAYS)
+            .saturating_mul(24 * 60 * 60),
+    );
+    let cutoff = std::time::SystemTime::now()
+        .checked_sub(max_age)
+        .unwrap_or(std::time::UNIX_EPOCH);
+
+    let dir_entries =
+        fs::read_dir(&directory).map_err(|error| format!("failed to read reports dir: {error}"))?;
+
+    let mut deleted = 0;
+    let mut freed_bytes = 0;
+
+    for dir_entry in dir_entries {
+        let dir_entry =
+            dir_entry.map_err(|error| format!("failed to read reports dir entry: {error}"))?;
+        let metadata = dir_entry
+            .metadata()
+            .map_err(|error| format!("failed to read report metadata: {error}"))?;
+
+        if !metadata.is_file()
+            || !dir_entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with("report-")
+        {
+            continue;
+        }
 
-    tray_icon
-        .set_menu(Some(tray_menu))
-        .map_err(|error| format!("failed to update tray menu: {error}"))?;
+        let modified = metadata
+            .modified()
+            .map_err(|error| format!("failed to read report modified time: {error}"))?;
+        if modified >= cutoff {
+            continue;
+        }
 
-    tray_icon
-        .set_visible(input.show_icon)
-        .map_err(|error| format!("failed to update tray icon visibility: {error}"))?;
+        fs::remove_file(dir_entry.path()).map_err(|error| {
+            format!(
+                "failed to delete report {}: {error}",
+                dir_entry.path().display()
+            )
+        })?;
+        deleted += 1;
+        freed_bytes += metadata.len();
+    }
 
-    Ok(())
+    Ok(PruneResult {
+        deleted,
+        freed_bytes,
+    })
 }
 
 #[tauri::command]
-fn reset_database(app: AppHandle) -> Result<(), String> {
-    let mut conn = open_db(&app)?;
+fn reset_database(app: AppHandle, db: State<'_, DbState>) -> Result<(), String> {
+    let mut conn = db.conn()?;
     let tx = conn
         .transaction()
         .map_err(|error| format!("failed to start reset transaction: {error}"))?;
@@ -1512,13 +6610,27 @@ fn reset_database(app: AppHandle) -> Result<(), String> {
         .map_err(|error| format!("failed to commit database reset: {error}"))?;
 
     ensure_default_categories_db(&conn)?;
+    emit_data_changed(&app, "all");
     Ok(())
 }
 
 fn main() {
     tauri::Builder::default()
         .setup(|app| {
-            let tray_menu = build_tray_menu(app, Some(DEFAULT_ADD_ITEM_SHORTCUT))?;
+            let (conn, recovery) = open_db(app.handle())?;
+            let menubar_settings = load_menubar_settings_db(&conn).unwrap_or_default();
+            app.manage(DbState(Mutex::new(conn)));
+            app.manage(DbRecoveryState(Mutex::new(recovery.clone())));
+
+            if let Some(recovery) = recovery {
+                let _ = app.emit("db-recovered", recovery);
+            }
+
+            let tray_menu = build_tray_menu(
+                app,
+                Some(&menubar_settings.add_item_shortcut),
+                menubar_settings.add_sprint_shortcut.as_deref(),
+            )?;
 
             let mut tray_builder = TrayIconBuilder::with_id(TRAY_ICON_ID)
                 .menu(&tray_menu)
@@ -1538,27 +6650,555 @@ fn main() {
                 tray_builder = tray_builder.icon(icon);
             }
 
-            tray_builder.build(app)?;
+            let tray_icon = tray_builder.build(app)?;
+            if !menubar_settings.show_icon {
+                tray_icon.set_visible(false)?;
+            }
+
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
             list_categories,
+            list_categories_used_in_sprint,
             create_category,
+            ensure_category,
             update_category,
             delete_category,
+            merge_categories,
+            rekey_category,
+            reorder_categories,
+            reorder_day_entries,
+            get_category_usage,
             list_sprints,
+            get_active_sprint,
+            get_active_sprint_id,
+            sprints_covering_date,
+            has_logged_today,
+            check_log_reminder,
             create_sprint,
+            create_sprint_with_entry,
+            duplicate_sprint,
             update_sprint_name,
+            update_sprint_description,
+            update_sprint_code,
+            update_sprint_dates,
+            set_sprint_archived,
+            auto_archive_sprints,
             delete_sprint,
+            clear_sprint_entries,
             list_entries_for_sprint,
+            list_entries_by_date_range,
+            list_entries_by_category,
+            list_entries_paged,
+            get_entry,
             add_daily_entry,
+            add_daily_entries_batch,
             update_daily_entry,
             delete_daily_entry,
+            import_entries_markdown,
+            import_entries_csv,
+            list_templates,
+            create_template,
+            delete_template,
+            list_report_templates,
+            save_report_template,
+            delete_report_template,
+            apply_template,
+            move_entry_to_sprint,
+            set_entry_date,
+            duplicate_entry,
+            add_entry_tag,
+            remove_entry_tag,
+            list_tags_for_sprint,
+            list_active_dates,
+            search_entries,
+            recent_entries,
+            get_sprint_stats,
+            get_burndown,
+            get_activity_stats,
+            get_weekday_distribution,
+            get_velocity_trend,
             generate_report,
+            generate_combined_report,
+            export_data_json,
+            export_entries_jsonl,
             get_data_path,
+            get_menubar_settings,
+            get_report_directory,
+            set_report_directory,
+            get_default_sprint_duration,
+            set_default_sprint_duration,
+            get_max_title_length,
+            set_max_title_length,
             update_menubar_settings,
             reset_database,
+            force_import_legacy,
+            validate_legacy_json,
+            preview_legacy_categories,
+            backup_database,
+            get_db_recovery,
+            restore_database,
+            vacuum_database,
+            get_db_diagnostics,
+            find_orphaned_entries,
+            repair_orphaned_entries,
+            get_schema_version,
+            list_reports,
+            prune_reports,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri app");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_conn() -> Connection {
+        let conn = Connection::open_in_memory().expect("open in-memory database");
+        init_schema(&conn).expect("initialize schema");
+        conn
+    }
+
+    #[test]
+    fn sprint_end_date_covers_a_single_day() {
+        assert_eq!(sprint_end_date("2024-07-01", 1).unwrap(), "2024-07-01");
+    }
+
+    #[test]
+    fn sprint_end_date_spans_three_weeks() {
+        assert_eq!(sprint_end_date("2024-07-01", 21).unwrap(), "2024-07-21");
+    }
+
+    #[test]
+    fn sprint_end_date_spans_thirty_days() {
+        assert_eq!(sprint_end_date("2024-07-01", 30).unwrap(), "2024-07-30");
+    }
+
+    #[test]
+    fn sprint_duration_outside_range_is_rejected() {
+        let expected = "duration_days must be between 1 and 90".to_string();
+
+        assert_eq!(validate_sprint_duration(0), Err(expected.clone()));
+        assert_eq!(validate_sprint_duration(91), Err(expected.clone()));
+        assert_eq!(sprint_end_date("2024-07-01", 0), Err(expected));
+        assert_eq!(validate_sprint_duration(90), Ok(90));
+    }
+
+    #[test]
+    fn legacy_import_recodes_sprint_codes_that_are_already_taken() {
+        let mut conn = test_conn();
+        conn.execute(
+            "INSERT INTO sprints (id, code, name, start_date, created_at)
+             VALUES ('local', 'SPR-001', 'Local', '2024-07-01', '2024-07-01T00:00:00Z')",
+            [],
+        )
+        .unwrap();
+
+        let legacy: AppData = serde_json::from_value(serde_json::json!({
+            "categories": [
+                { "id": "tasks", "name": "Tasks", "created_at": "2024-06-01T00:00:00Z" }
+            ],
+            "sprints": [{
+                "id": "legacy",
+                "code": "SPR-001",
+                "name": "Legacy",
+                "start_date": "2024-06-01",
+                "end_date": null,
+                "created_at": "2024-06-01T00:00:00Z"
+            }],
+            "entries": [{
+                "id": "entry-legacy",
+                "sprint_id": "legacy",
+                "date": "2024-06-02",
+                "category_id": "tasks",
+                "title": "Ship login fix",
+                "details": null,
+                "created_at": "2024-06-02T00:00:00Z"
+            }]
+        }))
+        .unwrap();
+
+        let report = import_legacy_data_db(&mut conn, legacy, LegacyConflictPolicy::Skip).unwrap();
+
+        assert_eq!(report.sprints.imported, 1);
+        assert_eq!(report.recoded_sprints, 1);
+        assert_eq!(report.entries.imported, 1);
+        assert_eq!(report.entries.skipped, 0);
+
+        let code: String = conn
+            .query_row("SELECT code FROM sprints WHERE id = 'legacy'", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_ne!(code, "SPR-001");
+    }
+
+    #[test]
+    fn sprint_code_normalization_runs_once_across_state_calls() {
+        let conn = test_conn();
+        conn.execute_batch(
+            "CREATE TEMP TABLE code_updates (sprint_id TEXT NOT NULL);
+             CREATE TEMP TRIGGER count_code_updates AFTER UPDATE OF code ON sprints
+             BEGIN
+                 INSERT INTO code_updates (sprint_id) VALUES (NEW.id);
+             END;
+             INSERT INTO sprints (id, code, name, start_date, created_at)
+             VALUES ('first', 'alpha', 'Alpha', '2024-07-01', '2024-07-01T00:00:00Z');",
+        )
+        .unwrap();
+
+        ensure_sprint_codes_db(&conn).unwrap();
+
+        let state = DbState(Mutex::new(conn));
+        {
+            let conn = state.conn().unwrap();
+            conn.execute(
+                "INSERT INTO sprints (id, code, name, start_date, created_at)
+                 VALUES ('second', 'beta', 'Beta', '2024-07-15', '2024-07-15T00:00:00Z')",
+                [],
+            )
+            .unwrap();
+        }
+
+        for _ in 0..5 {
+            let conn = state.conn().unwrap();
+            list_sprints_db(&conn, true).unwrap();
+            list_categories_db(&conn).unwrap();
+            pick_active_sprint_id(&conn).unwrap();
+            list_entries_for_sprint_db(&conn, "first", None, None).unwrap();
+        }
+
+        let conn = state.conn().unwrap();
+        let normalized: Vec<String> = conn
+            .prepare("SELECT sprint_id FROM code_updates")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(normalized, vec!["first".to_string()]);
+
+        let untouched: String = conn
+            .query_row("SELECT code FROM sprints WHERE id = 'second'", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(untouched, "beta");
+    }
+
+    #[test]
+    fn escape_markdown_escapes_each_special_character() {
+        let cases = [
+            ("a\\b", "a\\\\b"),
+            ("a`b", "a\\`b"),
+            ("a*b", "a\\*b"),
+            ("a_b", "a\\_b"),
+            ("a[b", "a\\[b"),
+            ("a]b", "a\\]b"),
+            ("a#b", "a\\#b"),
+            ("a<b", "a\\<b"),
+            ("a>b", "a\\>b"),
+            ("a|b", "a\\|b"),
+            ("a~b", "a\\~b"),
+        ];
+
+        for (raw, expected) in cases {
+            assert_eq!(escape_markdown(raw), expected, "escaping {raw:?}");
+        }
+    }
+
+    #[test]
+    fn escape_markdown_leaves_plain_text_alone() {
+        assert_eq!(
+            escape_markdown("Ship login fix (v2)."),
+            "Ship login fix (v2)."
+        );
+    }
+
+    #[test]
+    fn running_migrations_twice_is_a_no_op() {
+        let conn = test_conn();
+
+        let snapshot = |conn: &Connection| {
+            let version = schema_version_db(conn).unwrap();
+            let applied: i64 = conn
+                .query_row("SELECT COUNT(*) FROM schema_migrations", [], |row| {
+                    row.get(0)
+                })
+                .unwrap();
+            let schema: Vec<String> = conn
+                .prepare("SELECT sql FROM sqlite_master WHERE sql IS NOT NULL ORDER BY name")
+                .unwrap()
+                .query_map([], |row| row.get(0))
+                .unwrap()
+                .collect::<Result<_, _>>()
+                .unwrap();
+            (version, applied, schema)
+        };
+
+        let first = snapshot(&conn);
+        assert_eq!(first.0, MIGRATIONS.len() as i64);
+        assert_eq!(first.1, MIGRATIONS.len() as i64);
+
+        run_migrations(&conn).unwrap();
+        assert_eq!(snapshot(&conn), first);
+    }
+
+    #[test]
+    fn default_category_ids_stay_unique_when_slugs_collide() {
+        let mut taken = HashSet::new();
+        let ids = ["会议", "任务", "PR Reviews", "PR_Reviews"]
+            .iter()
+            .map(|name| defaults::unique_category_id(&slugify(name), &mut taken))
+            .collect::<Vec<_>>();
+
+        assert_eq!(ids, vec!["value", "value-2", "pr-reviews", "pr-reviews-2"]);
+    }
+
+    #[test]
+    fn multi_line_details_render_as_indented_block() {
+        let entry = DailyEntry {
+            id: "entry-1".to_string(),
+            sprint_id: "sprint-1".to_string(),
+            date: "2026-01-05".to_string(),
+            category_id: "task".to_string(),
+            title: "Ship login fix".to_string(),
+            details: Some("first line\n\nsecond *line*".to_string()),
+            created_at: String::new(),
+            updated_at: String::new(),
+            link: None,
+            seq: 0,
+            tags: Vec::new(),
+        };
+        let mut grouped = BTreeMap::new();
+        grouped
+            .entry(entry.date.clone())
+            .or_insert_with(BTreeMap::new)
+            .insert("Task".to_string(), vec![entry]);
+
+        let mut markdown = String::new();
+        push_markdown_groups(&mut markdown, grouped, ReportGrouping::Day, false, 2);
+
+        assert!(
+            markdown.contains("- Ship login fix\n  > first line\n  >\n  > second \\*line\\*\n"),
+            "unexpected markdown: {markdown}"
+        );
+    }
+
+    #[test]
+    fn set_entry_date_returns_entry_with_tags() {
+        let conn = test_conn();
+        conn.execute_batch(
+            "INSERT INTO categories (id, name, created_at)
+             VALUES ('cat-test', 'Test', '2024-07-01T00:00:00Z');
+             INSERT INTO sprints (id, code, name, start_date, end_date, created_at)
+             VALUES ('sprint-1', 'SPR-900', 'Sprint', '2024-07-01', '2024-07-14', '2024-07-01T00:00:00Z');
+             INSERT INTO entries (id, sprint_id, date, category_id, title, created_at)
+             VALUES ('entry-1', 'sprint-1', '2024-07-02', 'cat-test', 'Ship', '2024-07-02T00:00:00Z');
+             INSERT INTO entry_tags (entry_id, tag) VALUES ('entry-1', 'backend'), ('entry-1', 'urgent');",
+        )
+        .unwrap();
+
+        let output = set_entry_date_db(&conn, "entry-1", "2024-07-20").unwrap();
+
+        assert_eq!(output.entry.date, "2024-07-20");
+        assert_eq!(output.entry.tags, vec!["backend", "urgent"]);
+        assert!(!output.within_sprint_window);
+    }
+
+    #[test]
+    fn title_length_defaults_to_two_hundred_characters() {
+        let conn = test_conn();
+
+        assert_eq!(validate_title_length(&conn, &"a".repeat(200)), Ok(()));
+        assert_eq!(
+            validate_title_length(&conn, &"a".repeat(201)),
+            Err("title too long (max 200)".to_string())
+        );
+    }
+
+    #[test]
+    fn title_length_limit_follows_the_setting() {
+        let conn = test_conn();
+        set_setting_db(&conn, MAX_TITLE_LENGTH_SETTING, Some("300")).unwrap();
+
+        assert_eq!(validate_title_length(&conn, &"a".repeat(201)), Ok(()));
+        assert_eq!(
+            validate_title_length(&conn, &"a".repeat(301)),
+            Err("title too long (max 300)".to_string())
+        );
+    }
+
+    #[test]
+    fn report_date_range_validates_and_normalizes_dates() {
+        assert_eq!(report_date_range(None, Some(" ")), Ok((None, None)));
+        assert_eq!(
+            report_date_range(Some("2024-7-1"), Some("2024-07-14")),
+            Ok((
+                Some("2024-07-01".to_string()),
+                Some("2024-07-14".to_string())
+            ))
+        );
+        assert_eq!(
+            report_date_range(Some("07/01/2024"), None),
+            Err("from_date must be in YYYY-MM-DD format".to_string())
+        );
+        assert_eq!(
+            report_date_range(Some("2024-07-14"), Some("2024-07-01")),
+            Err("to_date must be on or after from_date".to_string())
+        );
+    }
+
+    #[test]
+    fn reassigned_category_entries_are_appended_to_the_target_day() {
+        let conn = test_conn();
+        conn.execute_batch(
+            "INSERT INTO categories (id, name, created_at) VALUES
+                 ('cat-source', 'Source', '2024-07-01T00:00:00Z'),
+                 ('cat-target', 'Target', '2024-07-01T00:00:00Z');
+             INSERT INTO sprints (id, code, name, start_date, created_at)
+             VALUES ('sprint-1', 'SPR-900', 'Sprint', '2024-07-01', '2024-07-01T00:00:00Z');
+             INSERT INTO entries (id, sprint_id, date, category_id, title, created_at, order_index) VALUES
+                 ('kept', 'sprint-1', '2024-07-02', 'cat-target', 'Kept', '2024-07-02T09:00:00Z', 0),
+                 ('second', 'sprint-1', '2024-07-02', 'cat-source', 'Second', '2024-07-02T08:00:00Z', 1),
+                 ('first', 'sprint-1', '2024-07-02', 'cat-source', 'First', '2024-07-02T10:00:00Z', 0);",
+        )
+        .unwrap();
+
+        reassign_category_entries_db(&conn, "cat-source", "cat-target").unwrap();
+
+        let order = list_entries_for_sprint_db(&conn, "sprint-1", None, None)
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.id)
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec!["kept", "first", "second"]);
+        assert_eq!(
+            next_order_index_db(&conn, "sprint-1", "2024-07-02", "cat-target"),
+            Ok(3)
+        );
+    }
+
+    #[test]
+    fn edited_entries_are_appended_to_their_new_group() {
+        let conn = test_conn();
+        conn.execute_batch(
+            "INSERT INTO categories (id, name, created_at) VALUES
+                 ('cat-source', 'Source', '2024-07-01T00:00:00Z'),
+                 ('cat-target', 'Target', '2024-07-01T00:00:00Z');
+             INSERT INTO sprints (id, code, name, start_date, created_at)
+             VALUES ('sprint-1', 'SPR-900', 'Sprint', '2024-07-01', '2024-07-01T00:00:00Z');
+             INSERT INTO entries (id, sprint_id, date, category_id, title, created_at, order_index) VALUES
+                 ('first', 'sprint-1', '2024-07-03', 'cat-target', 'First', '2024-07-03T09:00:00Z', 0),
+                 ('second', 'sprint-1', '2024-07-03', 'cat-target', 'Second', '2024-07-03T10:00:00Z', 1),
+                 ('moved', 'sprint-1', '2024-07-02', 'cat-source', 'Moved', '2024-07-02T08:00:00Z', 0);",
+        )
+        .unwrap();
+
+        let edit = |title: &str| UpdateDailyEntryInput {
+            id: "moved".to_string(),
+            date: "2024-07-03".to_string(),
+            category_id: "cat-target".to_string(),
+            title: title.to_string(),
+            details: None,
+            link: None,
+        };
+        update_entry_db(&conn, edit("Moved")).unwrap();
+        update_entry_db(&conn, edit("Moved again")).unwrap();
+
+        let order = list_entries_for_sprint_db(&conn, "sprint-1", None, None)
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.id)
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec!["first", "second", "moved"]);
+        assert_eq!(
+            next_order_index_db(&conn, "sprint-1", "2024-07-03", "cat-target"),
+            Ok(3)
+        );
+    }
+    #[test]
+    fn unreadable_database_file_is_not_intact() {
+        let dir = std::env::temp_dir().join(next_id("devlog-intact"));
+        fs::create_dir_all(&dir).unwrap();
+        let db_path = dir.join("daily-updates.sqlite");
+        fs::write(&db_path, "definitely not a sqlite database").unwrap();
+
+        let conn = Connection::open(&db_path).unwrap();
+        assert_eq!(database_is_intact(&conn), Ok(false));
+        assert_eq!(database_is_intact(&test_conn()), Ok(true));
+
+        drop(conn);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn quarantine_moves_sidecar_files_with_the_database() {
+        let dir = std::env::temp_dir().join(next_id("devlog-quarantine"));
+        fs::create_dir_all(&dir).unwrap();
+        let db_path = dir.join("daily-updates.sqlite");
+        fs::write(&db_path, "main").unwrap();
+        fs::write(dir.join("daily-updates.sqlite-journal"), "journal").unwrap();
+        fs::write(dir.join("daily-updates.sqlite-wal"), "wal").unwrap();
+
+        let quarantined_path = quarantine_db_file(&db_path).unwrap();
+        let quarantined = quarantined_path.display().to_string();
+
+        assert!(!db_path.exists());
+        assert!(!dir.join("daily-updates.sqlite-journal").exists());
+        assert!(!dir.join("daily-updates.sqlite-wal").exists());
+        assert_eq!(fs::read_to_string(&quarantined_path).unwrap(), "main");
+        assert_eq!(
+            fs::read_to_string(format!("{quarantined}-journal")).unwrap(),
+            "journal"
+        );
+        assert_eq!(
+            fs::read_to_string(format!("{quarantined}-wal")).unwrap(),
+            "wal"
+        );
+        assert!(!PathBuf::from(format!("{quarantined}-shm")).exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn csv_rows_report_the_line_each_record_starts_on() {
+        let rows = parse_csv_rows(
+            "date,category,title\n2024-07-01,Task,\"multi\nline\"\n2024-07-02,Task\n",
+        );
+        let lines = rows.iter().map(|(line, _)| *line).collect::<Vec<_>>();
+        assert_eq!(lines, vec![1, 2, 4]);
+
+        let Ok(fields) = &rows[2].1 else {
+            panic!("expected the last record to parse");
+        };
+        assert_eq!(
+            parse_csv_entry(&test_conn(), fields).err(),
+            Some("expected 3 or 4 columns, found 2".to_string())
+        );
+    }
+
+    #[test]
+    fn burndown_window_is_capped_and_stops_at_the_last_date() {
+        let start = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+        let far_future = NaiveDate::from_ymd_opt(9999, 12, 31).unwrap();
+        assert_eq!(
+            burndown_points(start, far_future, &BTreeMap::new()).err(),
+            Some(format!(
+                "burndown range is too long (max {MAX_BURNDOWN_DAYS} days)"
+            ))
+        );
+
+        let last = burndown_points(NaiveDate::MAX, NaiveDate::MAX, &BTreeMap::new()).unwrap();
+        assert_eq!(last.len(), 1);
+
+        let by_date = BTreeMap::from([(start, 2)]);
+        let points = burndown_points(start, start + Duration::days(2), &by_date).unwrap();
+        let cumulative = points
+            .iter()
+            .map(|point| point.cumulative)
+            .collect::<Vec<_>>();
+        assert_eq!(cumulative, vec![2, 2, 2]);
+    }
+}
@@ -0,0 +1,26 @@
+pub fn escape_markdown(raw: &str) -> String {
+    let mut escaped = String::with_capacity(raw.len());
+    for ch in raw.chars() {
+        if matches!(
+            ch,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '#' | '<' | '>' | '|' | '~'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+pub fn markdown_details_block(details: &str) -> String {
+    let mut block = String::new();
+    for line in details.trim_end().lines() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            block.push_str("  >\n");
+        } else {
+            block.push_str(&format!("  > {}\n", escape_markdown(line)));
+        }
+    }
+    block
+}
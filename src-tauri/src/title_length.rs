@@ -0,0 +1,29 @@
+use rusqlite::{params, Connection, OptionalExtension};
+
+const DEFAULT_MAX_TITLE_LENGTH: usize = 200;
+pub const MAX_TITLE_LENGTH_SETTING: &str = "max_title_length";
+
+pub fn max_title_length_db(conn: &Connection) -> Result<usize, String> {
+    let stored = conn
+        .query_row(
+            "SELECT value FROM settings WHERE key = ?1",
+            params![MAX_TITLE_LENGTH_SETTING],
+            |row| row.get::<_, String>(0),
+        )
+        .optional()
+        .map_err(|error| format!("failed to read setting {MAX_TITLE_LENGTH_SETTING}: {error}"))?;
+
+    Ok(stored
+        .and_then(|value| value.parse::<usize>().ok())
+        .filter(|value| *value > 0)
+        .unwrap_or(DEFAULT_MAX_TITLE_LENGTH))
+}
+
+pub fn validate_title_length(conn: &Connection, title: &str) -> Result<(), String> {
+    let max = max_title_length_db(conn)?;
+    if title.chars().count() > max {
+        return Err(format!("title too long (max {max})"));
+    }
+
+    Ok(())
+}
@@ -95,7 +95,9 @@ function pickActiveSprint(list: Sprint[]): Sprint | undefined {
   }
 
   const today = isoDateToday();
-  const newestFirst = [...list].sort((a, b) => b.created_at.localeCompare(a.created_at));
+  const newestFirst = list
+    .filter((sprint) => !sprint.archived)
+    .sort((a, b) => b.created_at.localeCompare(a.created_at));
 
   const ongoing = newestFirst.find((sprint) => {
     const startsOk = sprint.start_date <= today;
@@ -1408,7 +1410,7 @@ export default function App() {
       }
 
       if (event.payload === 'add_item_current_sprint') {
-        openAddItemFromShortcut();
+        void openAddItemFromShortcut();
       }
     });
 
@@ -1452,7 +1454,7 @@ export default function App() {
       }
 
       event.preventDefault();
-      openAddItemFromShortcut();
+      void openAddItemFromShortcut();
     };
 
     window.addEventListener('keydown', onKeyDown);
@@ -1626,8 +1628,14 @@ export default function App() {
     }, 80);
   }
 
-  function openAddItemFromShortcut() {
-    const active = pickActiveSprint(sprints);
+  async function openAddItemFromShortcut() {
+    let active: Sprint | undefined;
+    try {
+      active = (await api.getActiveSprint()) ?? undefined;
+    } catch {
+      active = pickActiveSprint(sprints);
+    }
+
     if (!active) {
       openCreateSprintFromShortcut();
       setNotice('No active sprint found. Create one first.');
@@ -1859,7 +1867,7 @@ export default function App() {
       setError('');
       setNotice('');
 
-      await api.deleteSprint({ id: sprint.id });
+      await api.deleteSprint({ id: sprint.id, force: true });
 
       const nextSprints = await api.listSprints();
       setSprints(nextSprints);
@@ -1990,7 +1998,8 @@ export default function App() {
           date: entryDate,
           category_id: entryCategoryId,
           title: entryTitle.trim(),
-          details: normalizedDetails
+          details: normalizedDetails,
+          link: entries.find((entry) => entry.id === editingEntryId)?.link ?? null
         });
       } else {
         await api.addDailyEntry({
@@ -2093,7 +2102,7 @@ export default function App() {
         categories: reportCategoryIds
       });
 
-      setReportMarkdown(output.markdown);
+      setReportMarkdown(output.content);
       setReportPath(output.file_path);
       setNotice(`Report generated (${output.total_items} items).`);
     } catch (err) {
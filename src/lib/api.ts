@@ -1,5 +1,37 @@
 import { invoke } from '@tauri-apps/api/core';
-import type { Category, DailyEntry, ReportOutput, Sprint } from './types';
+import type {
+  ActivityStats,
+  BatchResult,
+  BurndownPoint,
+  Category,
+  CategoryUsage,
+  DailyEntry,
+  DbDiagnostics,
+  DbRecovery,
+  EnsureCategoryOutput,
+  EntriesPage,
+  EntryTemplate,
+  ExportJsonlOutput,
+  ImportCsvOutput,
+  ImportMarkdownOutput,
+  ImportPreview,
+  LegacyImportReport,
+  MenubarSettings,
+  MoveEntryOutput,
+  OrphanReport,
+  PruneResult,
+  RecentEntry,
+  ReportFile,
+  ReportOutput,
+  ReportTemplate,
+  SetEntryDateOutput,
+  Sprint,
+  SprintSortOrder,
+  SprintWithEntryOutput,
+  SprintStats,
+  SprintVelocity,
+  VacuumResult
+} from './types';
 
 function tauriInvoke<T>(command: string, args?: Record<string, unknown>): Promise<T> {
   if (typeof window === 'undefined' || typeof (window as any).__TAURI_INTERNALS__?.invoke !== 'function') {
@@ -15,25 +47,87 @@ function tauriInvoke<T>(command: string, args?: Record<string, unknown>): Promis
 
 export const api = {
   listCategories: () => tauriInvoke<Category[]>('list_categories'),
-  createCategory: (payload: { name: string }) =>
+  listCategoriesUsedInSprint: (sprintId: string) =>
+    tauriInvoke<Category[]>('list_categories_used_in_sprint', { sprintId }),
+  createCategory: (payload: { name: string; color?: string | null; icon?: string | null }) =>
     tauriInvoke<Category>('create_category', { input: payload }),
-  updateCategory: (payload: { id: string; name: string }) =>
+  ensureCategory: (name: string) =>
+    tauriInvoke<EnsureCategoryOutput>('ensure_category', { input: { name } }),
+  updateCategory: (payload: {
+    id: string;
+    name: string;
+    color?: string | null;
+    icon?: string | null;
+  }) =>
     tauriInvoke<Category>('update_category', { input: payload }),
   deleteCategory: (payload: { id: string; replacement_category_id?: string | null }) =>
     tauriInvoke<void>('delete_category', { input: payload }),
+  mergeCategories: (payload: { source_id: string; target_id: string }) =>
+    tauriInvoke<Category>('merge_categories', { input: payload }),
+  rekeyCategory: (payload: { old_id: string; new_id: string }) =>
+    tauriInvoke<Category>('rekey_category', { input: payload }),
+  reorderCategories: (orderedIds: string[]) =>
+    tauriInvoke<Category[]>('reorder_categories', { input: { ordered_ids: orderedIds } }),
+  getCategoryUsage: () => tauriInvoke<CategoryUsage[]>('get_category_usage'),
 
-  listSprints: () => tauriInvoke<Sprint[]>('list_sprints'),
+  listSprints: (includeArchived = false, sortOrder?: SprintSortOrder | null) =>
+    tauriInvoke<Sprint[]>('list_sprints', { includeArchived, sortOrder: sortOrder ?? null }),
+  getActiveSprint: () => tauriInvoke<Sprint | null>('get_active_sprint'),
+  getActiveSprintId: () => tauriInvoke<string | null>('get_active_sprint_id'),
+  sprintsCoveringDate: (date: string) => tauriInvoke<Sprint[]>('sprints_covering_date', { date }),
   createSprint: (payload: {
     name?: string | null;
     start_date: string;
     duration_days?: number;
+    description?: string | null;
   }) => tauriInvoke<Sprint>('create_sprint', { input: payload }),
+  createSprintWithEntry: (payload: {
+    sprint: {
+      name?: string | null;
+      start_date: string;
+      duration_days?: number;
+      description?: string | null;
+    };
+    entry: { category_id: string; title: string; details?: string | null };
+  }) => tauriInvoke<SprintWithEntryOutput>('create_sprint_with_entry', { input: payload }),
+  duplicateSprint: (payload: {
+    source_sprint_id: string;
+    start_date: string;
+    duration_days?: number;
+  }) => tauriInvoke<Sprint>('duplicate_sprint', { input: payload }),
   updateSprintName: (payload: { id: string; name: string }) =>
     tauriInvoke<Sprint>('update_sprint_name', { input: payload }),
-  deleteSprint: (payload: { id: string }) => tauriInvoke<void>('delete_sprint', { input: payload }),
+  updateSprintDescription: (payload: { id: string; description: string | null }) =>
+    tauriInvoke<Sprint>('update_sprint_description', { input: payload }),
+  updateSprintCode: (payload: { id: string; code: string }) =>
+    tauriInvoke<Sprint>('update_sprint_code', { input: payload }),
+  updateSprintDates: (payload: { id: string; start_date: string; end_date?: string | null }) =>
+    tauriInvoke<Sprint>('update_sprint_dates', { input: payload }),
+  setSprintArchived: (payload: { id: string; archived: boolean }) =>
+    tauriInvoke<Sprint>('set_sprint_archived', { input: payload }),
+  autoArchiveSprints: (olderThanDays: number) =>
+    tauriInvoke<number>('auto_archive_sprints', { input: { older_than_days: olderThanDays } }),
+  deleteSprint: (payload: { id: string; force?: boolean }) =>
+    tauriInvoke<void>('delete_sprint', { input: payload }),
+  clearSprintEntries: (payload: { sprint_id: string; confirm_count: number }) =>
+    tauriInvoke<number>('clear_sprint_entries', { input: payload }),
 
-  listEntriesForSprint: (sprintId: string) =>
-    tauriInvoke<DailyEntry[]>('list_entries_for_sprint', { sprintId }),
+  listEntriesForSprint: (sprintId: string, fromDate?: string | null, toDate?: string | null) =>
+    tauriInvoke<DailyEntry[]>('list_entries_for_sprint', {
+      sprintId,
+      fromDate: fromDate ?? null,
+      toDate: toDate ?? null
+    }),
+  listEntriesByDateRange: (payload: {
+    from_date: string;
+    to_date: string;
+    categories?: string[] | null;
+  }) => tauriInvoke<DailyEntry[]>('list_entries_by_date_range', { input: payload }),
+  listEntriesByCategory: (payload: { sprint_id: string; category_id: string }) =>
+    tauriInvoke<DailyEntry[]>('list_entries_by_category', { input: payload }),
+  listEntriesPaged: (payload: { sprint_id: string; limit?: number; offset?: number }) =>
+    tauriInvoke<EntriesPage>('list_entries_paged', { input: payload }),
+  getEntry: (id: string) => tauriInvoke<DailyEntry>('get_entry', { id }),
 
   addDailyEntry: (payload: {
     sprint_id: string;
@@ -41,27 +135,141 @@ export const api = {
     category_id: string;
     title: string;
     details?: string | null;
+    link?: string | null;
+    reject_duplicates?: boolean;
   }) => tauriInvoke<DailyEntry>('add_daily_entry', { input: payload }),
+  addDailyEntriesBatch: (payload: {
+    entries: {
+      sprint_id: string;
+      date: string;
+      category_id: string;
+      title: string;
+      details?: string | null;
+      link?: string | null;
+      reject_duplicates?: boolean;
+    }[];
+  }) => tauriInvoke<BatchResult>('add_daily_entries_batch', { input: payload }),
   updateDailyEntry: (payload: {
     id: string;
     date: string;
     category_id: string;
     title: string;
     details?: string | null;
+    link?: string | null;
   }) => tauriInvoke<DailyEntry>('update_daily_entry', { input: payload }),
-  deleteDailyEntry: (payload: { id: string }) => tauriInvoke<void>('delete_daily_entry', { input: payload }),
+  deleteDailyEntry: (payload: { id: string }) =>
+    tauriInvoke<DailyEntry>('delete_daily_entry', { input: payload }),
+  reorderDayEntries: (payload: {
+    sprint_id: string;
+    date: string;
+    category_id: string;
+    ordered_entry_ids: string[];
+  }) => tauriInvoke<DailyEntry[]>('reorder_day_entries', { input: payload }),
+  moveEntryToSprint: (payload: { entry_id: string; target_sprint_id: string }) =>
+    tauriInvoke<MoveEntryOutput>('move_entry_to_sprint', { input: payload }),
+  setEntryDate: (payload: { entry_id: string; date: string }) =>
+    tauriInvoke<SetEntryDateOutput>('set_entry_date', { input: payload }),
+  duplicateEntry: (payload: { entry_id: string; new_date?: string | null }) =>
+    tauriInvoke<DailyEntry>('duplicate_entry', { input: payload }),
+  importEntriesMarkdown: (payload: { sprint_id: string; markdown: string }) =>
+    tauriInvoke<ImportMarkdownOutput>('import_entries_markdown', { input: payload }),
+  importEntriesCsv: (payload: { sprint_id: string; csv: string }) =>
+    tauriInvoke<ImportCsvOutput>('import_entries_csv', { input: payload }),
+  listTemplates: () => tauriInvoke<EntryTemplate[]>('list_templates'),
+  createTemplate: (payload: { category_id: string; title: string; details?: string | null }) =>
+    tauriInvoke<EntryTemplate>('create_template', { input: payload }),
+  deleteTemplate: (payload: { id: string }) =>
+    tauriInvoke<void>('delete_template', { input: payload }),
+  applyTemplate: (payload: { template_id: string; sprint_id: string; date: string }) =>
+    tauriInvoke<DailyEntry>('apply_template', { input: payload }),
+  listReportTemplates: () => tauriInvoke<ReportTemplate[]>('list_report_templates'),
+  saveReportTemplate: (payload: { name: string; header: string }) =>
+    tauriInvoke<ReportTemplate>('save_report_template', { input: payload }),
+  deleteReportTemplate: (payload: { name: string }) =>
+    tauriInvoke<void>('delete_report_template', { input: payload }),
+  addEntryTag: (payload: { entry_id: string; tag: string }) =>
+    tauriInvoke<string[]>('add_entry_tag', { input: payload }),
+  removeEntryTag: (payload: { entry_id: string; tag: string }) =>
+    tauriInvoke<string[]>('remove_entry_tag', { input: payload }),
+  listTagsForSprint: (sprintId: string) =>
+    tauriInvoke<string[]>('list_tags_for_sprint', { sprintId }),
+  listActiveDates: (sprintId: string) =>
+    tauriInvoke<[string, number][]>('list_active_dates', { sprintId }),
+  searchEntries: (payload: { query: string; sprint_id?: string | null; limit?: number }) =>
+    tauriInvoke<DailyEntry[]>('search_entries', { input: payload }),
+  recentEntries: (limit?: number) =>
+    tauriInvoke<RecentEntry[]>('recent_entries', { input: { limit: limit ?? null } }),
+  hasLoggedToday: () => tauriInvoke<boolean>('has_logged_today'),
+  checkLogReminder: () => tauriInvoke<boolean>('check_log_reminder'),
+
+  getSprintStats: (sprintId: string) => tauriInvoke<SprintStats>('get_sprint_stats', { sprintId }),
+  getBurndown: (sprintId: string) => tauriInvoke<BurndownPoint[]>('get_burndown', { sprintId }),
+  getActivityStats: () => tauriInvoke<ActivityStats>('get_activity_stats'),
+  getWeekdayDistribution: (sprintId?: string | null) =>
+    tauriInvoke<number[]>('get_weekday_distribution', { sprintId: sprintId ?? null }),
+  getVelocityTrend: (lastN?: number) =>
+    tauriInvoke<SprintVelocity[]>('get_velocity_trend', { input: { last_n: lastN ?? null } }),
 
   generateReport: (payload: {
     sprint_id: string;
     from_date?: string | null;
     to_date?: string | null;
     categories?: string[] | null;
+    format?: 'markdown' | 'csv' | 'html' | 'json' | 'pdf';
+    group_by?: 'day' | 'week';
+    write_file?: boolean;
+    include_toc?: boolean;
+    include_ids?: boolean;
+    include_summary?: boolean;
+    template_name?: string | null;
   }) => tauriInvoke<ReportOutput>('generate_report', { input: payload }),
+  generateCombinedReport: (payload: {
+    sprint_ids: string[];
+    format?: 'markdown';
+    from_date?: string | null;
+    to_date?: string | null;
+  }) => tauriInvoke<ReportOutput>('generate_combined_report', { input: payload }),
+
+  exportDataJson: () => tauriInvoke<string>('export_data_json'),
+  exportEntriesJsonl: (payload: { sprint_id?: string | null; out_path?: string | null }) =>
+    tauriInvoke<ExportJsonlOutput>('export_entries_jsonl', { input: payload }),
 
   getDataPath: () => tauriInvoke<string>('get_data_path'),
+  getReportDirectory: () => tauriInvoke<string>('get_report_directory'),
+  setReportDirectory: (path: string | null) =>
+    tauriInvoke<string>('set_report_directory', { path }),
+  getDefaultSprintDuration: () => tauriInvoke<number>('get_default_sprint_duration'),
+  setDefaultSprintDuration: (durationDays: number | null) =>
+    tauriInvoke<number>('set_default_sprint_duration', { durationDays }),
+  getMaxTitleLength: () => tauriInvoke<number>('get_max_title_length'),
+  setMaxTitleLength: (maxLength: number | null) =>
+    tauriInvoke<number>('set_max_title_length', { maxLength }),
+  getMenubarSettings: () => tauriInvoke<MenubarSettings>('get_menubar_settings'),
   updateMenubarSettings: (payload: {
     show_icon: boolean;
     add_item_shortcut?: string | null;
+    add_sprint_shortcut?: string | null;
   }) => tauriInvoke<void>('update_menubar_settings', { input: payload }),
-  resetDatabase: () => tauriInvoke<void>('reset_database')
+  resetDatabase: () => tauriInvoke<void>('reset_database'),
+  forceImportLegacy: (payload: { path: string; on_conflict: 'skip' | 'replace' }) =>
+    tauriInvoke<LegacyImportReport>('force_import_legacy', { input: payload }),
+  validateLegacyJson: (payload: { path: string }) =>
+    tauriInvoke<ImportPreview>('validate_legacy_json', { input: payload }),
+  previewLegacyCategories: (payload: { path: string }) =>
+    tauriInvoke<string[]>('preview_legacy_categories', { input: payload }),
+  backupDatabase: () => tauriInvoke<string>('backup_database'),
+  getDbRecovery: () => tauriInvoke<DbRecovery | null>('get_db_recovery'),
+  restoreDatabase: (payload: { path: string }) =>
+    tauriInvoke<void>('restore_database', { input: payload }),
+  vacuumDatabase: () => tauriInvoke<VacuumResult>('vacuum_database'),
+  getSchemaVersion: () => tauriInvoke<number>('get_schema_version'),
+  getDbDiagnostics: () => tauriInvoke<DbDiagnostics>('get_db_diagnostics'),
+  findOrphanedEntries: () => tauriInvoke<OrphanReport>('find_orphaned_entries'),
+  repairOrphanedEntries: (payload: { default_category_id: string }) =>
+    tauriInvoke<OrphanReport>('repair_orphaned_entries', { input: payload }),
+  listReports: () => tauriInvoke<ReportFile[]>('list_reports'),
+  pruneReports: (olderThanDays?: number) =>
+    tauriInvoke<PruneResult>('prune_reports', {
+      input: { older_than_days: olderThanDays ?? null }
+    })
 };
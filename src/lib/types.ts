@@ -2,6 +2,20 @@ export interface Category {
   id: string;
   name: string;
   created_at: string;
+  color?: string | null;
+  icon?: string | null;
+}
+
+export interface EnsureCategoryOutput {
+  category: Category;
+  created: boolean;
+}
+
+export interface CategoryUsage {
+  id: string;
+  name: string;
+  entry_count: number;
+  last_used_date?: string | null;
 }
 
 export interface Sprint {
@@ -11,8 +25,19 @@ export interface Sprint {
   start_date: string;
   end_date?: string | null;
   created_at: string;
+  archived?: boolean;
+  description?: string | null;
+  entry_count?: number;
 }
 
+export type SprintSortOrder =
+  | 'created'
+  | 'created_desc'
+  | 'start_date'
+  | 'start_date_desc'
+  | 'code'
+  | 'code_desc';
+
 export interface DailyEntry {
   id: string;
   sprint_id: string;
@@ -21,10 +46,185 @@ export interface DailyEntry {
   title: string;
   details?: string | null;
   created_at: string;
+  updated_at?: string;
+  link?: string | null;
+  seq?: number;
+  tags?: string[];
+}
+
+export interface SprintWithEntryOutput {
+  sprint: Sprint;
+  entry: DailyEntry;
+}
+
+export interface BatchResult {
+  inserted: number;
+  ids: string[];
+}
+
+export interface EntriesPage {
+  entries: DailyEntry[];
+  total_count: number;
+}
+
+export interface EntryTemplate {
+  id: string;
+  category_id: string;
+  title: string;
+  details?: string | null;
+  created_at: string;
+}
+
+export interface ReportTemplate {
+  name: string;
+  header: string;
+  created_at: string;
+}
+
+export interface SprintVelocity {
+  sprint_code: string;
+  sprint_name: string;
+  total_entries: number;
+  entries_per_day: number;
+}
+
+export interface ActivityStats {
+  current_streak: number;
+  longest_streak: number;
+  total_days_logged: number;
+}
+
+export interface ImportMarkdownOutput {
+  imported: number;
+  skipped: number;
+  created_categories: number;
+}
+
+export interface ImportCsvOutput {
+  imported: number;
+  skipped: number;
+  created_categories: number;
+  errors: string[];
+}
+
+export interface MoveEntryOutput {
+  entry: DailyEntry;
+  date_outside_window: boolean;
+}
+
+export interface SetEntryDateOutput {
+  entry: DailyEntry;
+  within_sprint_window: boolean;
+}
+
+export interface RecentEntry {
+  entry: DailyEntry;
+  sprint_code: string;
+  sprint_name: string;
+  category_name: string;
+}
+
+export interface ReportJson {
+  sprint: Sprint;
+  exported_at: string;
+  total_items: number;
+  days: {
+    date: string;
+    categories: {
+      name: string;
+      items: { title: string; details?: string | null }[];
+    }[];
+  }[];
 }
 
 export interface ReportOutput {
+  content: string;
   markdown: string;
   file_path: string;
   total_items: number;
 }
+
+export interface SprintStats {
+  total_entries: number;
+  entries_by_category: [string, number][];
+  entries_by_date: [string, number][];
+  active_days: number;
+}
+
+export interface BurndownPoint {
+  date: string;
+  entries_that_day: number;
+  cumulative: number;
+}
+
+export interface DbDiagnostics {
+  file_path: string;
+  file_size_bytes: number;
+  categories: number;
+  sprints: number;
+  entries: number;
+  schema_version: number;
+  page_count: number;
+  freelist_count: number;
+}
+
+export interface DbRecovery {
+  quarantined_path: string;
+  backup_path?: string | null;
+}
+
+export interface VacuumResult {
+  bytes_before: number;
+  bytes_after: number;
+  reclaimed: number;
+}
+
+export interface OrphanReport {
+  missing_sprint: number;
+  missing_category: number;
+}
+
+export interface ExportJsonlOutput {
+  file_path: string;
+  rows_written: number;
+}
+
+export interface ImportPreview {
+  categories: number;
+  sprints: number;
+  entries: number;
+  entries_missing_sprint: number;
+  entries_missing_category: number;
+  invalid_dates: number;
+}
+
+export interface ImportCounts {
+  imported: number;
+  skipped: number;
+}
+
+export interface LegacyImportReport {
+  categories: ImportCounts;
+  sprints: ImportCounts;
+  entries: ImportCounts;
+  recoded_sprints: number;
+}
+
+export interface ReportFile {
+  file_name: string;
+  path: string;
+  size_bytes: number;
+  created: string;
+  format: string;
+}
+
+export interface PruneResult {
+  deleted: number;
+  freed_bytes: number;
+}
+
+export interface MenubarSettings {
+  show_icon: boolean;
+  add_item_shortcut: string;
+  add_sprint_shortcut?: string | null;
+}